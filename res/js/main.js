@@ -1,7 +1,8 @@
-// Page state container
-let title = document.querySelector('title');
+// Page state container: the title in the app, where it can read it back, and a separate element
+// in standalone exports, which have a real title
+let state_container = document.getElementById('page-state') || document.querySelector('title');
 // Page state object
-let page_state = JSON.parse(title.innerHTML);
+let page_state = JSON.parse(state_container.innerHTML);
 
 // Update scroll position on load:
 window.scroll(0, page_state.scroll_top);
@@ -9,7 +10,7 @@ window.scroll(0, page_state.scroll_top);
 // Store scroll position on scroll:
 window.addEventListener('scroll', function() {
   page_state.scroll_top = window.pageYOffset;
-  title.innerHTML = JSON.stringify(page_state);
+  state_container.innerHTML = JSON.stringify(page_state);
 });
 
 // Set image sizes we have data for, store sizes for new images:
@@ -23,26 +24,76 @@ document.querySelectorAll('img').forEach(function(img) {
 
   img.style = style;
 
-  img.onload = function() {
+  const updateSize = function() {
     // Remove the style overloads on load in case the image has changed:
     img.style = "";
 
     // Cache calculated sizes:
-    page_state.image_heights[this.src] = this.height;
-    page_state.image_widths[this.src]  = this.width;
-    title.innerHTML = JSON.stringify(page_state);
+    page_state.image_heights[img.src] = img.height;
+    page_state.image_widths[img.src]  = img.width;
+    state_container.innerHTML = JSON.stringify(page_state);
   };
+
+  if (img.complete && img.naturalWidth > 0) {
+    // Already loaded, so there won't be a load event to unpin the old size:
+    updateSize();
+  } else {
+    img.onload = updateSize;
+  }
 });
 
-// Create anchors for all the headings:
-document.querySelectorAll('h1, h2, h3, h4, h5, h6').forEach(function(heading) {
-  if (!heading.id) {
-    let content = heading.innerHTML.trim().toLowerCase();
-    let slug    = content.replace(/[^\w\d]+/g, '-');
+// Scroll to the last block that starts at or before the given line of the source. Pages without
+// source lines are left where they are:
+function scroll_to_line(line) {
+  const elements = document.querySelectorAll('[data-source-line]');
+  let target = null;
 
-    heading.id = slug;
+  if (elements.length == 0) {
+    return;
   }
-});
+
+  elements.forEach(function(element) {
+    if (parseInt(element.dataset.sourceLine, 10) <= line) {
+      target = element;
+    }
+  });
+
+  if (target) {
+    target.scrollIntoView();
+  } else {
+    window.scroll({top: 0});
+  }
+}
+
+// Scroll to the element with the given id. Sanitized HTML has a "user-content-" prefix on its
+// ids, like on GitHub, but links to them don't:
+function scroll_to_anchor(id) {
+  const target = document.getElementById(id) || document.getElementById(`user-content-${id}`);
+
+  if (target) {
+    target.scrollIntoView();
+  }
+}
+
+// The browser doesn't know about the prefix when the page is loaded with a fragment:
+if (window.location.hash) {
+  scroll_to_anchor(decodeURIComponent(window.location.hash.slice(1)));
+}
+
+// Typeset the math in the page with KaTeX, once it's loaded:
+function render_math() {
+  document.querySelectorAll('.math').forEach(function(element) {
+    const display   = element.classList.contains('math-display');
+    const delimiter = display ? '$$' : '$';
+    const source    = element.textContent.slice(delimiter.length, -delimiter.length);
+
+    katex.render(source, element, {
+      displayMode:  display,
+      output:       'mathml',
+      throwOnError: false,
+    });
+  });
+}
 
 // Show link preview at the bottom:
 let linkPreview = document.querySelector('#link-preview');
@@ -55,6 +106,19 @@ document.querySelectorAll('a').forEach(function(link) {
   if (url.startsWith(rootUrl)) {
     // it's a local anchor, let's just take that part
     description = `<strong>Jump</strong>: ${url.replace(rootUrl, '')}`
+
+    link.addEventListener('click', function(e) {
+      const id = decodeURIComponent(link.hash.slice(1));
+
+      // the target might only have the id with a prefix, which the browser won't find
+      if (!document.getElementById(id)) {
+        e.preventDefault();
+        scroll_to_anchor(id);
+      }
+    });
+  } else if (link.protocol == 'file:' && /\.(md|markdown|rst|rest|adoc|asciidoc)$/i.test(link.pathname)) {
+    // it's a local document, the app will open it
+    description = `<strong>Open</strong>: ${link.getAttribute('href')}`
   } else {
     // it's an external URL, copy it
     description = `<strong>Copy</strong>: ${url}`
@@ -0,0 +1,1028 @@
+//! AsciiDoc rendering.
+//!
+//! A native renderer for the parts of AsciiDoc that READMEs and documentation tend to use: section
+//! titles, paragraphs, bullet, numbered and description lists, listing and literal blocks, quotes,
+//! tables, admonitions, links, cross references, images and inline formatting. Attribute entries
+//! like `:name: value` are collected, so `{name}` references to them are replaced. Comments and
+//! block macros it doesn't know about are skipped.
+//!
+//! The output is the same [`RenderedContent`] that the markdown renderer produces, the same way
+//! the reStructuredText renderer does it: headings get the same kind of ids, code is highlighted
+//! the same way, and admonitions like `NOTE:` are shown like GitHub-style alerts in markdown.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{PathBuf, Path};
+
+use pulldown_cmark::escape::{escape_href, escape_html};
+use regex::{Captures, Regex};
+
+use crate::input::Config;
+use crate::markdown::{self, Heading, RenderedContent, Slugger};
+use crate::render::Render;
+use crate::rst;
+
+/// The kinds of admonitions, which happen to be the same as the kinds of GitHub's alerts.
+///
+const ADMONITION_TYPES: &[&str] = &["NOTE", "TIP", "IMPORTANT", "WARNING", "CAUTION"];
+
+/// Encapsulates an AsciiDoc file and provides an interface to turn its contents into HTML.
+///
+pub struct Renderer {
+    /// The original path given to the renderer.
+    pub adoc_path: PathBuf,
+
+    /// The canonicalized path to use in file operations.
+    pub canonical_adoc_path: PathBuf,
+
+    sanitize_html: bool,
+    source_lines: bool,
+    resolve_links: bool,
+}
+
+impl Renderer {
+    /// Create a new renderer instance that wraps the given AsciiDoc file.
+    ///
+    pub fn new(adoc_path: PathBuf, config: &Config) -> Self {
+        let canonical_adoc_path = adoc_path.canonicalize().
+            unwrap_or_else(|_| adoc_path.clone());
+        let sanitize_html = config.sanitize_html;
+
+        Renderer {
+            adoc_path, canonical_adoc_path, sanitize_html,
+            source_lines: false,
+            resolve_links: true,
+        }
+    }
+
+    /// Give the top-level blocks of the document a `data-source-line` attribute with the
+    /// (1-based) line of the file they start on, like the markdown renderer does.
+    ///
+    pub fn with_source_lines(mut self) -> Self {
+        self.source_lines = true;
+        self
+    }
+
+    /// Keep relative links as they're written, for a standalone export, like
+    /// [`markdown::Renderer::with_relative_links`] does.
+    ///
+    pub fn with_relative_links(mut self) -> Self {
+        self.resolve_links = false;
+        self
+    }
+
+    /// Turn the current contents of the file into HTML.
+    ///
+    pub fn run(&self) -> Result<RenderedContent, io::Error> {
+        let contents = fs::read_to_string(&self.canonical_adoc_path)?;
+        let root_dir = self.canonical_adoc_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let lines: Vec<&str> = contents.lines().map(str::trim_end).collect();
+        let mut parser = BlockParser {
+            inline:       InlineParser::new(&contents, root_dir, self.resolve_links),
+            slugger:      Slugger::new(),
+            headings:     Vec::new(),
+            languages:    HashSet::new(),
+            source_lines: self.source_lines,
+        };
+
+        let mut output = String::new();
+        parser.blocks(&lines, &mut output);
+
+        if self.sanitize_html {
+            output = markdown::sanitize_html(&output);
+        }
+
+        Ok(RenderedContent {
+            html: output,
+            code_languages: parser.languages,
+            headings: parser.headings,
+            front_matter: None,
+            local_images: parser.inline.local_images,
+            has_diagrams: false,
+            has_math: false,
+        })
+    }
+}
+
+impl Render for Renderer {
+    fn canonical_path(&self) -> &Path {
+        &self.canonical_adoc_path
+    }
+
+    fn run(&self) -> Result<RenderedContent, io::Error> {
+        Renderer::run(self)
+    }
+}
+
+/// The attributes, id and title given to a block on the lines before it, like `[source,rust]`,
+/// `[[anchor]]` or `.Title`.
+///
+#[derive(Debug, Default)]
+struct BlockMetadata<'a> {
+    style: Option<&'a str>,
+    positional: Vec<&'a str>,
+    options: Vec<&'a str>,
+    id: Option<&'a str>,
+    title: Option<&'a str>,
+}
+
+impl<'a> BlockMetadata<'a> {
+    /// Add the attributes in a `[...]` line. The first positional attribute is the block's style,
+    /// which can have an id and options attached to it, like `[source#id%linenums,rust]`.
+    ///
+    fn add_attributes(&mut self, attributes: &'a str) {
+        let (positional, named) = parse_attributes(attributes);
+
+        if let Some(first) = positional.first() {
+            let style_end = first.find(['#', '.', '%']).unwrap_or(first.len());
+            if style_end > 0 {
+                self.style = Some(&first[..style_end]);
+            }
+
+            let mut rest = &first[style_end..];
+            while let Some(kind) = rest.chars().next() {
+                let value_end = rest[1..].find(['#', '.', '%']).map_or(rest.len(), |i| i + 1);
+                match kind {
+                    '#' => self.id = Some(&rest[1..value_end]),
+                    '%' => self.options.push(&rest[1..value_end]),
+                    _   => (),
+                }
+                rest = &rest[value_end..];
+            }
+        }
+
+        self.positional = positional;
+        if let Some(id) = named.get("id") {
+            self.id = Some(id);
+        }
+        if let Some(options) = named.get("options").or_else(|| named.get("opts")) {
+            self.options.extend(options.split(',').map(str::trim));
+        }
+    }
+
+}
+
+/// Splits lines into blocks and renders them, keeping track of the state that's shared across the
+/// whole document.
+///
+struct BlockParser {
+    inline: InlineParser,
+    slugger: Slugger,
+    headings: Vec<Heading>,
+    languages: HashSet<String>,
+    // Only the top-level blocks are annotated, the ones in delimited blocks are rendered separately
+    source_lines: bool,
+}
+
+impl BlockParser {
+    fn blocks(&mut self, lines: &[&str], output: &mut String) {
+        let source_lines = std::mem::replace(&mut self.source_lines, false);
+        // The positions in the output where blocks start, with the lines they come from
+        let mut block_starts = Vec::new();
+        let mut metadata = BlockMetadata::default();
+        let mut metadata_start = None;
+        let mut index = 0;
+
+        while index < lines.len() {
+            let line = lines[index];
+
+            if line.is_empty() {
+                index += 1;
+                continue;
+            }
+
+            // Line comments, but not the delimiters of comment blocks
+            if line.starts_with("//") && !line.starts_with("///") {
+                index += 1;
+                continue;
+            }
+
+            // Attribute entries have already been collected by the inline parser
+            if is_attribute_entry(line) {
+                index += 1;
+                continue;
+            }
+
+            if let Some(id) = line.strip_prefix("[[").and_then(|l| l.strip_suffix("]]")) {
+                metadata.id = id.split(',').next();
+                metadata_start.get_or_insert(index);
+                index += 1;
+                continue;
+            }
+
+            if let Some(attributes) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                metadata.add_attributes(attributes);
+                metadata_start.get_or_insert(index);
+                index += 1;
+                continue;
+            }
+
+            if let Some(title) = block_title(line) {
+                metadata.title = Some(title);
+                metadata_start.get_or_insert(index);
+                index += 1;
+                continue;
+            }
+
+            if source_lines {
+                block_starts.push((output.len(), metadata_start.unwrap_or(index) + 1));
+            }
+
+            let metadata = std::mem::take(&mut metadata);
+            metadata_start = None;
+            index = self.block(lines, index, metadata, output);
+        }
+
+        // Going backwards, so inserting attributes doesn't move the positions that are left.
+        // Blocks that render nothing, like comments, share a position with the next one.
+        let mut last_position = None;
+        for (position, line) in block_starts.into_iter().rev() {
+            if last_position != Some(position) {
+                rst::add_source_line(output, position, line);
+                last_position = Some(position);
+            }
+        }
+
+        self.source_lines = source_lines;
+    }
+
+    /// Render the block that starts at `start`, given the metadata on the lines before it, and
+    /// return the index of the line after it.
+    ///
+    fn block(
+        &mut self,
+        lines: &[&str],
+        start: usize,
+        metadata: BlockMetadata,
+        output: &mut String,
+    ) -> usize {
+        let line = lines[start];
+
+        if let Some((level, title)) = section_title(line) {
+            self.heading(title, level, metadata.id, output);
+
+            // The header of the document can have author and revision lines after its title
+            if level == 1 && self.headings.len() == 1 {
+                return lines[start..].iter().
+                    position(|l| l.is_empty()).
+                    map_or(lines.len(), |p| start + p);
+            }
+            return start + 1;
+        }
+
+        if let Some(title) = metadata.title {
+            output.push_str(&format!("<p><strong>{}</strong></p>\n", self.inline.to_html(title)));
+        }
+
+        if is_thematic_break(line) {
+            output.push_str("<hr />\n");
+            return start + 1;
+        }
+
+        if line == "<<<" {
+            return start + 1;
+        }
+
+        if let Some(language) = line.strip_prefix("```") {
+            let (content, end) = delimited_block(lines, start, "```");
+            let language = language.split(',').next().filter(|l| !l.is_empty());
+            self.code_block(language, content, output);
+            return end;
+        }
+
+        if is_delimiter(line) {
+            let (content, end) = delimited_block(lines, start, line);
+            self.delimited_block(line, content, &metadata, output);
+            return end;
+        }
+
+        if let Some(macro_line) = line.strip_prefix("image::") {
+            if let Some((target, attributes)) = parse_macro(macro_line) {
+                output.push_str(&format!("<p>{}</p>\n", self.inline.image(target, attributes)));
+            }
+            return start + 1;
+        }
+
+        // Other block macros, like `toc::[]` or `include::file[]`
+        let is_block_macro = line.split_once("::").
+            is_some_and(|(name, rest)| is_macro_name(name) && parse_macro(rest).is_some());
+        if is_block_macro {
+            return start + 1;
+        }
+
+        if list_item(line).is_some() {
+            return self.list(lines, start, &[], output);
+        }
+
+        if description_item(line).is_some() {
+            return self.description_list(lines, start, output);
+        }
+
+        // A paragraph that starts with an admonition label, like `NOTE: Text`
+        let admonition = line.split_once(": ").
+            filter(|(label, _)| ADMONITION_TYPES.contains(label));
+        if let Some((label, text)) = admonition {
+            let end = paragraph_end(lines, start);
+            let mut content = vec![text];
+            content.extend(&lines[start + 1..end]);
+
+            self.admonition(label, &content, output);
+            return end;
+        }
+
+        // An indented paragraph is shown as it's written
+        if line.starts_with(char::is_whitespace) || metadata.style == Some("literal") {
+            let end = paragraph_end(lines, start);
+            let min_indent = lines[start..end].iter().map(|l| indentation(l)).min().unwrap_or(0);
+            let content: Vec<&str> = lines[start..end].iter().map(|l| &l[min_indent..]).collect();
+
+            self.code_block(None, &content, output);
+            return end;
+        }
+
+        let end = paragraph_end(lines, start);
+        let content = &lines[start..end];
+
+        match metadata.style {
+            Some(style) if ADMONITION_TYPES.contains(&style) => {
+                self.admonition(style, content, output);
+            },
+            Some("source" | "listing") => {
+                self.code_block(metadata.positional.get(1).copied(), content, output);
+            },
+            Some("quote" | "verse") => {
+                output.push_str("<blockquote>\n");
+                self.paragraph(content, output);
+                output.push_str("</blockquote>\n");
+            },
+            _ => self.paragraph(content, output),
+        }
+
+        end
+    }
+
+    fn heading(&mut self, title: &str, level: u32, id: Option<&str>, output: &mut String) {
+        let text = self.inline.to_text(title);
+        let id = match id {
+            Some(id) => self.slugger.reserve(id),
+            None     => self.slugger.slug(&text),
+        };
+        let heading = Heading { level, id, text };
+
+        output.push_str(&heading.opening_tag(&[]));
+        output.push_str(&self.inline.to_html(title));
+        output.push_str(&format!("</h{}>\n", level));
+
+        self.headings.push(heading);
+    }
+
+    /// A line ending in ` +` is followed by a line break.
+    ///
+    fn paragraph(&mut self, lines: &[&str], output: &mut String) {
+        let html = self.inline.to_html(&lines.join("\n")).replace(" +\n", "<br />\n");
+        output.push_str(&format!("<p>{}</p>\n", html));
+    }
+
+    /// Render the contents of a block between two delimiter lines, like `----` or `====`. What
+    /// it's rendered as depends on the delimiter and on the style of the block.
+    ///
+    fn delimited_block(
+        &mut self,
+        delimiter: &str,
+        content: &[&str],
+        metadata: &BlockMetadata,
+        output: &mut String,
+    ) {
+        let admonition = metadata.style.filter(|style| ADMONITION_TYPES.contains(style));
+
+        match (delimiter.chars().next(), admonition) {
+            (Some('/'), _) => (),
+            (Some('|'), _) => self.table(content, metadata, output),
+            (Some('+'), _) => {
+                output.push_str(&content.join("\n"));
+                output.push('\n');
+            },
+            (Some('.'), _) => self.code_block(None, content, output),
+            (Some('-'), None) if delimiter != "--" => {
+                let language = match metadata.style {
+                    Some("source") | None => metadata.positional.get(1).copied(),
+                    Some(_)               => None,
+                };
+                self.code_block(language, content, output);
+            },
+            (Some('_'), _) => {
+                output.push_str("<blockquote>\n");
+                self.blocks(content, output);
+                output.push_str("</blockquote>\n");
+            },
+            (_, Some(label)) => self.admonition(label, content, output),
+            // Examples, sidebars and open blocks are just shown as their contents
+            _ => self.blocks(content, output),
+        }
+    }
+
+    fn admonition(&mut self, label: &str, content: &[&str], output: &mut String) {
+        let alert_type = label.to_lowercase();
+        // Admonition labels are never empty, and they're ASCII
+        let mut title = alert_type.clone();
+        title[..1].make_ascii_uppercase();
+
+        output.push_str(&format!(
+            "<div class=\"markdown-alert markdown-alert-{}\">\n<p class=\"markdown-alert-title\">{}</p>\n",
+            alert_type, title
+        ));
+        self.blocks(content, output);
+        output.push_str("</div>\n");
+    }
+
+    /// Render code, highlighting it with syntect if the language is known, or leaving that to the
+    /// client side, like the markdown renderer does.
+    ///
+    fn code_block(&mut self, language: Option<&str>, lines: &[&str], output: &mut String) {
+        let mut code = lines.join("\n");
+        code.push('\n');
+
+        if let Some(language) = language {
+            self.languages.insert(language.to_string());
+        }
+        output.push_str(&markdown::render_code(language, code));
+    }
+
+    /// Render the list that starts at `start`, along with the lists nested in it, and return the
+    /// index of the line after it. Items with a marker that's different from the list's own start
+    /// a nested list, unless the marker belongs to one of the lists this one is nested in, given
+    /// in `parent_markers`.
+    ///
+    fn list(
+        &mut self,
+        lines: &[&str],
+        start: usize,
+        parent_markers: &[&str],
+        output: &mut String,
+    ) -> usize {
+        // [Unwrap] Only called on a line with a list marker
+        let (marker, _) = list_item(lines[start]).unwrap();
+        let tag = if marker == "." { "ol" } else { "ul" };
+        let mut markers = parent_markers.to_vec();
+        markers.push(marker);
+
+        output.push_str(&format!("<{}>\n", tag));
+        let mut index = start;
+
+        while let Some((_, text)) = lines.get(index).
+            and_then(|l| list_item(l)).
+            filter(|(m, _)| *m == marker)
+        {
+            let end = paragraph_end(lines, index);
+            let mut text = text.to_string();
+            for line in &lines[index + 1..end] {
+                text.push('\n');
+                text.push_str(line.trim_start());
+            }
+            index = end;
+
+            output.push_str("<li>");
+            output.push_str(&self.list_item_html(&text));
+
+            // Blocks attached to the item with a `+` line
+            while lines.get(index) == Some(&"+") {
+                let block_start = index + 1;
+                let block_end = attached_block_end(lines, block_start);
+
+                output.push('\n');
+                self.blocks(&lines[block_start..block_end], output);
+                index = block_end;
+            }
+
+            // Items can be separated by blank lines
+            loop {
+                let next_item = lines[index..].iter().position(|l| !l.is_empty()).
+                    and_then(|p| list_item(lines[index + p]).map(|(m, _)| (index + p, m)));
+
+                match next_item {
+                    Some((next, next_marker)) if !markers.contains(&next_marker) => {
+                        output.push('\n');
+                        index = self.list(lines, next, &markers, output);
+                    },
+                    Some((next, next_marker)) if next_marker == marker => {
+                        index = next;
+                        break;
+                    },
+                    _ => break,
+                }
+            }
+
+            output.push_str("</li>\n");
+        }
+
+        output.push_str(&format!("</{}>\n", tag));
+        index
+    }
+
+    /// Items of checklists, starting with `[x]` or `[ ]`, get a checkbox like markdown tasks.
+    ///
+    fn list_item_html(&self, text: &str) -> String {
+        let checkbox = [("[ ] ", ""), ("[x] ", " checked=\"\""), ("[*] ", " checked=\"\"")].
+            into_iter().
+            find_map(|(marker, checked)| text.strip_prefix(marker).map(|rest| (checked, rest)));
+
+        match checkbox {
+            Some((checked, rest)) => format!(
+                "<input disabled=\"\" type=\"checkbox\"{}/>\n{}",
+                checked, self.inline.to_html(rest)
+            ),
+            None => self.inline.to_html(text),
+        }
+    }
+
+    /// Render the description list that starts at `start` and return the index of the line after
+    /// it. A description can be on the same line as its term, on the lines after it, or it can
+    /// be a list.
+    ///
+    fn description_list(&mut self, lines: &[&str], start: usize, output: &mut String) -> usize {
+        output.push_str("<dl>\n");
+        let mut index = start;
+
+        while let Some((term, text)) = lines.get(index).and_then(|l| description_item(l)) {
+            output.push_str(&format!("<dt>{}</dt>\n", self.inline.to_html(term)));
+
+            let end = paragraph_end(lines, index);
+            let mut description: Vec<&str> = text.into_iter().collect();
+            description.extend(lines[index + 1..end].iter().map(|l| l.trim_start()));
+            index = end;
+
+            let next_line = lines[index..].iter().position(|l| !l.is_empty()).map(|p| index + p);
+
+            if !description.is_empty() {
+                let description = self.inline.to_html(&description.join("\n"));
+                output.push_str(&format!("<dd>{}</dd>\n", description));
+            } else if let Some(next) = next_line.filter(|n| list_item(lines[*n]).is_some()) {
+                output.push_str("<dd>\n");
+                index = self.list(lines, next, &[], output);
+                output.push_str("</dd>\n");
+            }
+
+            match lines[index..].iter().position(|l| !l.is_empty()).map(|p| index + p) {
+                Some(next) if description_item(lines[next]).is_some() => index = next,
+                _ => break,
+            }
+        }
+
+        output.push_str("</dl>\n");
+        index
+    }
+
+    /// Render a table from the lines between its `|===` delimiters. Every cell starts with a `|`,
+    /// and the number of columns is the number of cells on the first line. That line is the
+    /// header if it's followed by a blank line, or if the table has the `header` option.
+    ///
+    fn table(&mut self, lines: &[&str], metadata: &BlockMetadata, output: &mut String) {
+        let first_line = lines.iter().position(|l| !l.is_empty()).unwrap_or(lines.len());
+        let column_count = lines.get(first_line).map_or(0, |l| table_cells(l).len()).max(1);
+        let has_header = metadata.options.contains(&"header") ||
+            (first_line + 1 < lines.len() && lines[first_line + 1].is_empty());
+
+        let cells: Vec<&str> = lines.iter().flat_map(|l| table_cells(l)).collect();
+        let mut rows = cells.chunks(column_count);
+
+        output.push_str("<table>");
+        let header = if has_header { rows.next() } else { None };
+        if let Some(row) = header {
+            output.push_str("<thead><tr>");
+            for cell in row {
+                output.push_str(&format!("<th>{}</th>", self.inline.to_html(cell)));
+            }
+            output.push_str("</tr></thead>");
+        }
+
+        output.push_str("<tbody>\n");
+        for row in rows {
+            output.push_str("<tr>");
+            for cell in row {
+                output.push_str(&format!("<td>{}</td>", self.inline.to_html(cell)));
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str("</tbody></table>\n");
+    }
+}
+
+/// Renders the markup inside paragraphs, headings and list items: bold, italic and monospace
+/// text, links, cross references, images and references to document attributes.
+///
+struct InlineParser {
+    re_markup: Regex,
+    re_attribute_reference: Regex,
+    re_url_scheme: Regex,
+    root_dir: PathBuf,
+    resolve_links: bool,
+    attributes: HashMap<String, String>,
+    local_images: HashSet<PathBuf>,
+}
+
+impl InlineParser {
+    fn new(contents: &str, root_dir: &Path, resolve_links: bool) -> Self {
+        // [Unwrap] The regexes are valid
+        let re_markup = Regex::new(concat!(
+            r"\\(?P<escaped>[\\*_`+#{\[<])",
+            r"|\+\+\+(?P<raw>.+?)\+\+\+",
+            r"|\+\+(?P<unconstrained_passthrough>.+?)\+\+",
+            r"|\B\+(?P<passthrough>[^+\s](?:[^+]*[^+\s])?)\+\B",
+            r"|``(?P<unconstrained_literal>.+?)``",
+            r"|\B`(?P<literal>[^`\s](?:[^`]*[^`\s])?)`\B",
+            r"|\*\*(?P<unconstrained_strong>.+?)\*\*",
+            r"|\B\*(?P<strong>[^*\s](?:[^*]*[^*\s])?)\*\B",
+            r"|__(?P<unconstrained_emphasis>.+?)__",
+            r"|\b_(?P<emphasis>[^_\s](?:[^_]*[^_\s])?)_\b",
+            r"|##(?P<unconstrained_mark>.+?)##",
+            r"|\B#(?P<mark>[^#\s](?:[^#]*[^#\s])?)#\B",
+            r"|image:(?P<image>[^\s:\[][^\s\[]*)\[(?P<image_attributes>[^\]]*)\]",
+            r"|(?:link:(?P<link_target>[^\s\[]+)",
+            r"|(?P<link_url>\b(?:https?|ftp|irc|mailto):[^\s\[]+))\[(?P<link_text>[^\]]*)\]",
+            r"|<<(?P<xref>[^\s,>]+)(?:,\s*(?P<xref_text>[^>]+))?>>",
+            r#"|(?P<url>\bhttps?://[^\s\[<>]*[^\s\[<>.,;:!?)'"])"#,
+        )).unwrap();
+        let re_attribute_reference = Regex::new(r"\\?\{(?P<name>\w[\w-]*)\}").unwrap();
+        let re_url_scheme = Regex::new(r"^[a-zA-Z][a-zA-Z\d+.-]*:").unwrap();
+
+        let re_attribute_entry =
+            Regex::new(r"(?m)^:(?P<name>\w[\w-]*):[ \t]*(?P<value>.*?)[ \t]*$").unwrap();
+        let attributes = re_attribute_entry.captures_iter(contents).
+            map(|captures| (captures["name"].to_string(), captures["value"].to_string())).
+            collect();
+
+        InlineParser {
+            re_markup, re_attribute_reference, re_url_scheme, attributes,
+            root_dir: root_dir.to_path_buf(),
+            resolve_links,
+            local_images: HashSet::new(),
+        }
+    }
+
+    fn to_html(&self, text: &str) -> String {
+        self.render(&self.replace_attributes(text), true)
+    }
+
+    /// The text without any markup, for the outline and heading ids.
+    ///
+    fn to_text(&self, text: &str) -> String {
+        self.render(&self.replace_attributes(text), false)
+    }
+
+    /// Replace references to attributes with their values. Unknown and escaped references are
+    /// left as they are.
+    ///
+    fn replace_attributes(&self, text: &str) -> String {
+        self.re_attribute_reference.replace_all(text, |captures: &Captures| {
+            match self.attributes.get(&captures["name"]) {
+                Some(value) if !captures[0].starts_with('\\') => value.clone(),
+                _ => captures[0].to_string(),
+            }
+        }).into_owned()
+    }
+
+    fn render(&self, text: &str, html: bool) -> String {
+        let mut output = String::new();
+        let mut last_end = 0;
+
+        for captures in self.re_markup.captures_iter(text) {
+            // [Unwrap] The full match is always present
+            let markup = captures.get(0).unwrap();
+
+            self.push_text(&mut output, &text[last_end..markup.start()], html);
+            self.push_markup(&mut output, &captures, html);
+            last_end = markup.end();
+        }
+
+        self.push_text(&mut output, &text[last_end..], html);
+        output
+    }
+
+    fn push_markup(&self, output: &mut String, captures: &Captures, html: bool) {
+        let group = |names: &[&str]| {
+            names.iter().find_map(|name| captures.name(name)).map(|m| m.as_str())
+        };
+
+        if let Some(escaped) = captures.name("escaped") {
+            // The backslash is not rendered, and the character isn't markup
+            self.push_text(output, escaped.as_str(), html);
+        } else if let Some(raw) = captures.name("raw") {
+            output.push_str(raw.as_str());
+        } else if let Some(text) = group(&["unconstrained_passthrough", "passthrough"]) {
+            self.push_text(output, text, html);
+        } else if let Some(code) = group(&["unconstrained_literal", "literal"]) {
+            let mut content = String::new();
+            self.push_text(&mut content, code, html);
+            push_tag(output, "code", &content, html);
+        } else if let Some(content) = group(&["unconstrained_strong", "strong"]) {
+            push_tag(output, "strong", &self.render(content, html), html);
+        } else if let Some(content) = group(&["unconstrained_emphasis", "emphasis"]) {
+            push_tag(output, "em", &self.render(content, html), html);
+        } else if let Some(content) = group(&["unconstrained_mark", "mark"]) {
+            push_tag(output, "mark", &self.render(content, html), html);
+        } else if let Some(target) = captures.name("image") {
+            if html {
+                output.push_str(&self.image_html(target.as_str(), &captures["image_attributes"]));
+            } else {
+                let (positional, _) = parse_attributes(&captures["image_attributes"]);
+                output.push_str(positional.first().unwrap_or(&""));
+            }
+        } else if let Some(id) = captures.name("xref") {
+            let text = captures.name("xref_text").map_or(id.as_str(), |t| t.as_str().trim());
+            self.push_link(output, &format!("#{}", id.as_str()), &self.render(text, html), html);
+        } else if let Some(url) = captures.name("url") {
+            let mut text = String::new();
+            self.push_text(&mut text, url.as_str(), html);
+            self.push_link(output, url.as_str(), &text, html);
+        } else {
+            // [Unwrap] That's the only kind of markup left
+            let url = group(&["link_target", "link_url"]).unwrap();
+            // A caret at the end means the link opens in a new window, which isn't needed here
+            let text = match captures["link_text"].trim_end_matches('^') {
+                "" => {
+                    let mut text = String::new();
+                    self.push_text(&mut text, url.strip_prefix("mailto:").unwrap_or(url), html);
+                    text
+                },
+                text => self.render(text, html),
+            };
+
+            self.push_link(output, url, &text, html);
+        }
+    }
+
+    /// Push a link with already rendered text, or only the text if the output is not HTML.
+    ///
+    fn push_link(&self, output: &mut String, url: &str, text: &str, html: bool) {
+        if !html {
+            output.push_str(text);
+            return;
+        }
+
+        output.push_str("<a href=\"");
+        // [Unwrap] Writing to a String should not fail
+        escape_href(&mut *output, &self.resolve_link(url)).unwrap();
+        output.push_str("\">");
+        output.push_str(text);
+        output.push_str("</a>");
+    }
+
+    fn push_text(&self, output: &mut String, text: &str, html: bool) {
+        if html {
+            // [Unwrap] Writing to a String should not fail
+            escape_html(&mut *output, text).unwrap();
+        } else {
+            output.push_str(text);
+        }
+    }
+
+    fn image(&mut self, target: &str, attributes: &str) -> String {
+        if let Some(path) = rst::local_path(target, &self.re_url_scheme) {
+            self.local_images.insert(markdown::local_file_path(&self.root_dir, path));
+        }
+
+        self.image_html(target, attributes)
+    }
+
+    /// An `<img>` tag for an image macro. Its positional attributes are the alt text, the width
+    /// and the height, and they can also be given by name.
+    ///
+    fn image_html(&self, target: &str, attributes: &str) -> String {
+        let (positional, named) = parse_attributes(attributes);
+        let attribute = |name: &str, position: usize| {
+            named.get(name).copied().or_else(|| positional.get(position).copied())
+        };
+
+        let mut output = String::from("<img src=\"");
+        // [Unwrap] Writing to a String should not fail
+        escape_href(&mut output, &self.resolve_url(target)).unwrap();
+        output.push_str("\" alt=\"");
+        escape_html(&mut output, attribute("alt", 0).unwrap_or_default()).unwrap();
+        output.push('"');
+
+        for (index, name) in ["width", "height"].into_iter().enumerate() {
+            if let Some(value) = attribute(name, index + 1).filter(|v| !v.is_empty()) {
+                output.push_str(&format!(" {}=\"", name));
+                escape_html(&mut output, value).unwrap();
+                output.push('"');
+            }
+        }
+
+        output.push_str(" />");
+        output
+    }
+
+    /// Links are resolved like images, unless they're meant to be kept relative.
+    ///
+    fn resolve_link(&self, url: &str) -> String {
+        if self.resolve_links { self.resolve_url(url) } else { url.to_string() }
+    }
+
+    /// Relative URLs are turned into `file://` ones in the document's directory, the same way the
+    /// markdown renderer does it.
+    ///
+    fn resolve_url(&self, url: &str) -> String {
+        match rst::local_path(url, &self.re_url_scheme) {
+            Some(path) => format!("file://{}/{}", self.root_dir.display(), path),
+            None => url.to_string(),
+        }
+    }
+}
+
+/// Split the contents of an attribute list, like the `[...]` of a block or a macro, into the
+/// positional attributes and the named ones, which look like `name=value`. Quotes around values
+/// are removed.
+///
+fn parse_attributes(attributes: &str) -> (Vec<&str>, HashMap<&str, &str>) {
+    let mut positional = Vec::new();
+    let mut named = HashMap::new();
+
+    for attribute in attributes.split(',').map(str::trim) {
+        match attribute.split_once('=') {
+            Some((name, value)) if is_macro_name(name.trim()) => {
+                named.insert(name.trim(), value.trim().trim_matches('"'));
+            },
+            _ => positional.push(attribute.trim_matches('"')),
+        }
+    }
+
+    // No attributes at all, rather than one empty one
+    if positional == [""] {
+        positional.clear();
+    }
+
+    (positional, named)
+}
+
+/// Push already rendered content, wrapped in the given tag if the output is HTML.
+///
+fn push_tag(output: &mut String, tag: &str, content: &str, html: bool) {
+    if html {
+        output.push_str(&format!("<{}>{}</{}>", tag, content, tag));
+    } else {
+        output.push_str(content);
+    }
+}
+
+/// The target and attributes of a macro, given the part after its name, like `path[alt]`.
+///
+fn parse_macro(line: &str) -> Option<(&str, &str)> {
+    let (target, attributes) = line.strip_suffix(']')?.split_once('[')?;
+
+    if target.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((target, attributes))
+}
+
+/// The level and text of a section title, like `== Section`. There can be up to six `=` signs,
+/// or `#` signs, like in markdown. A single one is the title of the whole document.
+///
+fn section_title(line: &str) -> Option<(u32, &str)> {
+    let marker = line.chars().next().filter(|c| *c == '=' || *c == '#')?;
+    let level = line.chars().take_while(|c| *c == marker).count();
+    let title = line[level..].strip_prefix(' ')?.trim();
+
+    if level > 6 || title.is_empty() {
+        return None;
+    }
+    Some((level as u32, title))
+}
+
+/// A block title is a line that starts with a `.` that's followed by text, like `.Example`.
+///
+fn block_title(line: &str) -> Option<&str> {
+    let title = line.strip_prefix('.')?;
+    title.starts_with(|c: char| !c.is_whitespace() && c != '.').then_some(title)
+}
+
+fn is_attribute_entry(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix(':') else { return false };
+
+    match rest.split_once(':') {
+        Some((name, value)) => {
+            let name = name.trim_start_matches('!').trim_end_matches('!');
+            is_macro_name(name) && (value.is_empty() || value.starts_with(' '))
+        },
+        None => false,
+    }
+}
+
+fn is_macro_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Thematic breaks are usually three single quotes, but markdown-style ones work as well.
+///
+fn is_thematic_break(line: &str) -> bool {
+    matches!(line, "'''" | "---" | "***" | "- - -" | "* * *")
+}
+
+/// Delimiters of blocks are lines of four or more of the same character, like `----` or `====`,
+/// the `--` of an open block, or the `|===` of a table.
+///
+fn is_delimiter(line: &str) -> bool {
+    let mut chars = line.chars();
+    let Some(first) = chars.next() else { return false };
+
+    line == "--" || line == "|===" ||
+        (line.len() >= 4 && "-./=_*+".contains(first) && chars.all(|c| c == first))
+}
+
+/// The lines between the delimiter at `start` and the closing one, which is the same line, and
+/// the index of the line after the closing delimiter. A block that's not closed goes on until the
+/// end of the document.
+///
+fn delimited_block<'a, 'b>(
+    lines: &'a [&'b str],
+    start: usize,
+    delimiter: &str,
+) -> (&'a [&'b str], usize) {
+    match lines[start + 1..].iter().position(|l| *l == delimiter) {
+        Some(offset) => (&lines[start + 1..start + 1 + offset], start + offset + 2),
+        None => (&lines[start + 1..], lines.len()),
+    }
+}
+
+/// The index of the line after the paragraph that starts at `start`. Paragraphs end with a blank
+/// line, a block delimiter, or a `+` that attaches a block to a list item.
+///
+fn paragraph_end(lines: &[&str], start: usize) -> usize {
+    lines.iter().enumerate().skip(start + 1).
+        find(|(_, l)| {
+            l.is_empty() || **l == "+" || is_delimiter(l) ||
+                list_item(l).is_some() || description_item(l).is_some()
+        }).
+        map_or(lines.len(), |(index, _)| index)
+}
+
+/// The index of the line after a block that's attached to a list item, including the metadata
+/// lines before it.
+///
+fn attached_block_end(lines: &[&str], start: usize) -> usize {
+    let mut index = start;
+    let is_metadata = |line: &str| {
+        (line.starts_with('[') && line.ends_with(']')) || block_title(line).is_some()
+    };
+    while lines.get(index).is_some_and(|l| is_metadata(l)) {
+        index += 1;
+    }
+
+    match lines.get(index) {
+        Some(line) if is_delimiter(line) => delimited_block(lines, index, line).1,
+        Some(line) if line.starts_with("```") => delimited_block(lines, index, "```").1,
+        Some(_) => paragraph_end(lines, index),
+        None => index,
+    }
+}
+
+/// If the line is a list item, get its marker and its text. Bullet lists use `*` or `-`, and
+/// numbered ones use `.` or numbers like `1.`, which are treated the same way. Nested lists
+/// repeat the marker, like `**` or `..`.
+///
+fn list_item(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let (marker, text) = line.split_once(' ')?;
+    let text = text.trim_start();
+
+    if text.is_empty() {
+        return None;
+    }
+
+    let repeats = |c: char| marker.len() <= 5 && marker.chars().all(|m| m == c);
+    let is_number = |n: &str| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit());
+
+    if marker == "-" || repeats('*') || repeats('.') {
+        Some((marker, text))
+    } else if marker.strip_suffix('.').is_some_and(is_number) {
+        Some((".", text))
+    } else {
+        None
+    }
+}
+
+/// If the line is an item of a description list, like `Term:: Description`, get its term and its
+/// description, if it's on the same line.
+///
+fn description_item(line: &str) -> Option<(&str, Option<&str>)> {
+    let (term, description) = line.split_once(":: ").
+        map(|(term, description)| (term, Some(description.trim()))).
+        or_else(|| line.strip_suffix("::").map(|term| (term, None)))?;
+
+    if term.is_empty() || term.starts_with(char::is_whitespace) || term.ends_with(':') {
+        return None;
+    }
+    Some((term, description.filter(|d| !d.is_empty())))
+}
+
+/// The cells of a row of a table, the text after each `|`.
+///
+fn table_cells(line: &str) -> Vec<&str> {
+    line.split('|').skip(1).map(str::trim).collect()
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
@@ -5,39 +5,87 @@
 //! body, which makes reloading smoother (update the file, refresh).
 //!
 //! For the other assets, it means the HTML can refer to local files instead of embedding the
-//! contents as `<script>` and `<style>` tags, making the output easier to read and debug.
+//! contents as `<script>` and `<style>` tags, making the output easier to read and debug. This
+//! includes highlight.js, mermaid.js and KaTeX, which are bundled, so code highlighting, diagrams
+//! and math work without a network connection.
+//!
+//! For exporting, everything can instead be inlined into a single standalone HTML file.
 
 use std::collections::HashMap;
 use std::fmt::Write;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use log::{debug, warn};
+use pulldown_cmark::escape::escape_html;
+use regex::{Captures, Regex};
 use serde::{Serialize, Deserialize};
 use tempfile::{tempdir, TempDir};
 
 use crate::input::Config;
-use crate::markdown::RenderedContent;
+use crate::markdown::{self, RenderedContent};
+
+const MAIN_JS:       &str  = include_str!("../res/js/main.js");
+const MAIN_CSS:      &str  = include_str!("../res/style/main.css");
+const GITHUB_CSS:    &str  = include_str!("../res/style/github.css");
+const HIGHLIGHT_JS:  &str  = include_str!("../res/js/highlight.min.js");
+const HIGHLIGHT_CSS: &str  = include_str!("../res/style/highlight-github.css");
+const MERMAID_JS:    &str  = include_str!("../res/js/mermaid.min.js");
+const KATEX_JS:      &str  = include_str!("../res/js/katex.min.js");
+const SYNTAX_CSS:    &str  = include_str!("../res/style/syntax-github.css");
+const ICON_PNG:      &[u8] = include_bytes!("../res/icon.png");
 
-const MAIN_JS:    &str  = include_str!("../res/js/main.js");
-const MAIN_CSS:   &str  = include_str!("../res/style/main.css");
-const GITHUB_CSS: &str  = include_str!("../res/style/github.css");
-const ICON_PNG:   &[u8] = include_bytes!("../res/icon.png");
+/// The names and aliases of the languages in the bundled build of highlight.js, the way
+/// `hljs.listLanguages()` and the `aliases` of each language list them. Code in any other
+/// language is only highlighted if syntect knows it.
+///
+const HIGHLIGHT_JS_LANGUAGES: &[&str] = &[
+    "apache", "apacheconf", "armasm", "arm", "bash", "sh", "zsh", "c", "cc", "h", "c++", "h++",
+    "hpp", "hh", "hxx", "cxx", "c-like", "coffeescript", "coffee", "cson", "iced", "cpp",
+    "csharp", "cs", "c#", "css", "d", "diff", "patch", "go", "golang", "handlebars", "hbs",
+    "html.hbs", "html.handlebars", "htmlbars", "haskell", "hs", "http", "https", "ini", "toml",
+    "java", "jsp", "javascript", "js", "jsx", "mjs", "cjs", "json", "julia", "kotlin", "kt",
+    "less", "lua", "makefile", "mk", "mak", "markdown", "md", "mkdown", "mkd", "nginx",
+    "nginxconf", "nim", "nix", "nixos", "objectivec", "mm", "objc", "obj-c", "perl", "pl", "pm",
+    "php", "php3", "php4", "php5", "php6", "php7", "php-template", "plaintext", "text", "txt",
+    "properties", "python", "py", "gyp", "ipython", "python-repl", "pycon", "r", "ruby", "rb",
+    "gemspec", "podspec", "thor", "irb", "rust", "rs", "scala", "scss", "shell", "console",
+    "sql", "swift", "typescript", "ts", "x86asm", "xml", "html", "xhtml", "rss", "atom", "xjb",
+    "xsd", "xsl", "plist", "wsf", "svg", "yaml", "yml",
+];
 
-/// The version of highlight.js the app uses for code highlighting.
+/// The version of highlight.js the app uses for code highlighting. It's the official build that
+/// bundles the core library with its common languages, listed in [`HIGHLIGHT_JS_LANGUAGES`],
+/// stored in `res/js/highlight.min.js`. Before it was bundled, the app loaded version 9.18.1 from
+/// a CDN, one language pack at a time, but 10.1.1 is the version of the build that's vendored.
 ///
 /// More details about the tool at <https://highlightjs.org/>
 ///
-pub const HIGHLIGHT_JS_VERSION: &str = "9.18.1";
+pub const HIGHLIGHT_JS_VERSION: &str = "10.1.1";
+
+/// The version of mermaid.js the app uses to draw diagrams, stored in `res/js/mermaid.min.js`.
+///
+/// More details about the tool at <https://mermaid-js.github.io/>
+///
+pub const MERMAID_JS_VERSION: &str = "9.1.5";
+
+/// The version of KaTeX the app uses to typeset math, stored in `res/js/katex.min.js`. Math is
+/// rendered as MathML, which the webview displays natively, so no fonts or CSS are needed.
+///
+/// More details about the tool at <https://katex.org/>
+///
+pub const KATEX_VERSION: &str = "0.16.4";
 
 /// The client-side state of the page as the user's interacted with it. Currently, includes the
 /// scroll position and the dimensions of images on the page, so that reloading doesn't change the
 /// viewport.
 ///
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PageState {
     /// Scroll position of the page.
     pub scroll_top: f64,
@@ -92,6 +140,16 @@ impl Assets {
             unwrap_or_else(|e| warn!("{}", e));
         fs::write(output_path.join("github.css"), GITHUB_CSS).
             unwrap_or_else(|e| warn!("{}", e));
+        fs::write(output_path.join("highlight.min.js"), HIGHLIGHT_JS).
+            unwrap_or_else(|e| warn!("{}", e));
+        fs::write(output_path.join("highlight-github.css"), HIGHLIGHT_CSS).
+            unwrap_or_else(|e| warn!("{}", e));
+        fs::write(output_path.join("mermaid.min.js"), MERMAID_JS).
+            unwrap_or_else(|e| warn!("{}", e));
+        fs::write(output_path.join("katex.min.js"), KATEX_JS).
+            unwrap_or_else(|e| warn!("{}", e));
+        fs::write(output_path.join("syntax-github.css"), SYNTAX_CSS).
+            unwrap_or_else(|e| warn!("{}", e));
         fs::write(output_path.join("icon.png"), ICON_PNG).
             unwrap_or_else(|e| warn!("{}", e));
 
@@ -119,29 +177,9 @@ impl Assets {
                 String::from("{}")
             });
 
-        let mut hl_tags = String::new();
-        if !content.code_languages.is_empty() {
-            let root_url = format!(
-                "https://cdnjs.cloudflare.com/ajax/libs/highlight.js/{}",
-                HIGHLIGHT_JS_VERSION
-            );
-
-            // [Unwrap] Writing to a String should not fail
-            writeln!(hl_tags, r#"<link rel="stylesheet" href="{}/styles/github.min.css" />"#, root_url).
-                unwrap();
-            writeln!(hl_tags, r#"<script src="{}/highlight.min.js"></script>"#, root_url).
-                unwrap();
-
-            for language in &content.code_languages {
-                writeln!(
-                    hl_tags,
-                    r#"<script src="{}/languages/{}.min.js"></script>"#,
-                    root_url, language
-                ).unwrap();
-            }
-
-            writeln!(hl_tags, r#"<script>hljs.initHighlighting()</script>"#).unwrap();
-        }
+        let hl_tags = Self::build_hl_tags(content, false);
+        let diagram_tags = Self::build_diagram_tags(content, false);
+        let math_tags = Self::build_math_tags(content, false);
 
         debug!("Building HTML:");
         debug!(" > custom_css_path = {:?}", custom_css_path);
@@ -153,6 +191,8 @@ impl Assets {
             custom_css_path = custom_css_path.display(),
             body            = content.html,
             hl_tags         = hl_tags,
+            diagram_tags    = diagram_tags,
+            math_tags       = math_tags,
             page_state      = json_state,
         };
 
@@ -162,6 +202,55 @@ impl Assets {
         Ok(html_path)
     }
 
+    /// Build a single self-contained HTML file out of the given content and write it to
+    /// `output_file`, with the given `title`. All the CSS and Javascript is embedded in the page,
+    /// including the user's custom CSS if there is any, and local images are inlined as `data:`
+    /// URLs.
+    ///
+    /// This doesn't need an output directory, so it can be used without initializing any assets.
+    ///
+    pub fn build_standalone(
+        content: &RenderedContent,
+        title: &str,
+        output_file: &Path,
+    ) -> anyhow::Result<()> {
+        let custom_css_path = Config::css_path();
+        let custom_css = fs::read_to_string(&custom_css_path).unwrap_or_default();
+
+        // [Unwrap] The default state has nothing that can fail to serialize
+        let json_state = serde_json::to_string(&PageState::default()).unwrap();
+        let hl_tags = Self::build_hl_tags(content, true);
+        let diagram_tags = Self::build_diagram_tags(content, true);
+        let math_tags = Self::build_math_tags(content, true);
+
+        let mut escaped_title = String::new();
+        // [Unwrap] Writing to a String should not fail
+        escape_html(&mut escaped_title, title).unwrap();
+
+        debug!("Building standalone HTML:");
+        debug!(" > custom_css_path = {:?}", custom_css_path);
+        debug!(" > code languages  = {:?}", content.code_languages);
+        debug!(" > output_file     = {:?}", output_file);
+
+        let page = format! {
+            include_str!("../res/export_layout.html"),
+            title        = escaped_title,
+            github_css   = GITHUB_CSS,
+            syntax_css   = SYNTAX_CSS,
+            main_css     = MAIN_CSS,
+            custom_css   = custom_css,
+            body         = inline_local_images(&content.html),
+            main_js      = MAIN_JS,
+            hl_tags      = hl_tags,
+            diagram_tags = diagram_tags,
+            math_tags    = math_tags,
+            page_state   = json_state,
+        };
+
+        fs::write(output_file, page.as_bytes())?;
+        Ok(())
+    }
+
     /// The path on the filesystem where the HTML and other assets go. Could be a temporary
     /// directory, or the one given at construction time.
     ///
@@ -173,6 +262,85 @@ impl Assets {
         }
     }
 
+    /// The tags that load highlight.js for the code blocks that the renderer couldn't highlight.
+    /// With `inline` set, the library and its styles are embedded in the tags. Code in languages
+    /// that neither of them knows is left as it is, with a warning.
+    ///
+    fn build_hl_tags(content: &RenderedContent, inline: bool) -> String {
+        let mut hl_tags = String::new();
+        if content.code_languages.is_empty() {
+            return hl_tags;
+        }
+
+        for language in content.code_languages.iter().filter(|l| !is_highlighted(l)) {
+            warn!("No highlighting available for code in: {}", language);
+        }
+
+        // [Unwrap] Writing to a String should not fail
+        if inline {
+            writeln!(hl_tags, "<style>\n{}\n</style>", HIGHLIGHT_CSS).unwrap();
+            writeln!(hl_tags, "<script>\n{}\n</script>", HIGHLIGHT_JS).unwrap();
+        } else {
+            writeln!(hl_tags, r#"<link rel="stylesheet" href="highlight-github.css" />"#).unwrap();
+            writeln!(hl_tags, r#"<script src="highlight.min.js"></script>"#).unwrap();
+        }
+
+        // Most code blocks are highlighted by the renderer, highlight.js only picks up the rest
+        writeln!(
+            hl_tags,
+            r#"<script>document.querySelectorAll('pre:not(.highlighted) code').forEach(hljs.highlightBlock)</script>"#
+        ).unwrap();
+
+        hl_tags
+    }
+
+    /// The tags that load mermaid.js to draw the diagrams in the document, if it has any. With
+    /// `inline` set, the library is embedded in the tags.
+    ///
+    fn build_diagram_tags(content: &RenderedContent, inline: bool) -> String {
+        let mut diagram_tags = String::new();
+        if !content.has_diagrams {
+            return diagram_tags;
+        }
+
+        // [Unwrap] Writing to a String should not fail
+        if inline {
+            writeln!(diagram_tags, "<script>\n{}\n</script>", MERMAID_JS).unwrap();
+        } else {
+            writeln!(diagram_tags, r#"<script src="mermaid.min.js"></script>"#).unwrap();
+        }
+
+        // Draws every `<pre class="mermaid">` once the page has loaded
+        writeln!(
+            diagram_tags,
+            "<script>mermaid.initialize({{ startOnLoad: true }})</script>"
+        ).unwrap();
+
+        diagram_tags
+    }
+
+    /// The tags that load KaTeX to typeset the math in the document, if it has any. With
+    /// `inline` set, the library is embedded in the tags.
+    ///
+    fn build_math_tags(content: &RenderedContent, inline: bool) -> String {
+        let mut math_tags = String::new();
+        if !content.has_math {
+            return math_tags;
+        }
+
+        // [Unwrap] Writing to a String should not fail
+        if inline {
+            writeln!(math_tags, "<script>\n{}\n</script>", KATEX_JS).unwrap();
+        } else {
+            writeln!(math_tags, r#"<script src="katex.min.js"></script>"#).unwrap();
+        }
+
+        // The function is defined in main.js, which is loaded before these tags
+        writeln!(math_tags, "<script>render_math()</script>").unwrap();
+
+        math_tags
+    }
+
     /// Delete the temporary directory used for building assets, if there is one. This should
     /// happen automatically on drop, but a GTK-level exit doesn't seem to unroll the stack, so we
     /// may need to delete things explicitly.
@@ -189,3 +357,70 @@ impl Assets {
         }
     }
 }
+
+/// True if code in the given language is highlighted, either by syntect while rendering, or by
+/// the bundled highlight.js. Like both of them, this only looks at the first word of the language.
+///
+fn is_highlighted(language: &str) -> bool {
+    let token = language.split_whitespace().next().unwrap_or_default();
+
+    markdown::SYNTAX_SET.find_syntax_by_token(token).is_some() ||
+        HIGHLIGHT_JS_LANGUAGES.contains(&token.to_lowercase().as_str())
+}
+
+/// Replace all `file://` image sources in the given HTML with `data:` URLs that contain the
+/// images themselves. Images that can't be read are left as they are, with a warning.
+///
+fn inline_local_images(html: &str) -> String {
+    let re_local_image = Regex::new(r#"<img src="file://([^"]+)""#).unwrap();
+
+    re_local_image.replace_all(html, |captures: &Captures| {
+        let path = PathBuf::from(decode_url_path(&captures[1]));
+
+        match fs::read(&path) {
+            Ok(bytes) => {
+                let mime_type = match path.extension().and_then(|e| e.to_str()) {
+                    Some("svg")          => "image/svg+xml",
+                    Some("jpg" | "jpeg") => "image/jpeg",
+                    Some("gif")          => "image/gif",
+                    Some("webp")         => "image/webp",
+                    _                    => "image/png",
+                };
+                format!(r#"<img src="data:{};base64,{}""#, mime_type, BASE64.encode(bytes))
+            },
+            Err(e) => {
+                warn!("Couldn't inline image {}: {}", path.display(), e);
+                captures[0].to_string()
+            },
+        }
+    }).into_owned()
+}
+
+/// Undo the escaping that's applied to URLs in the rendered HTML: entities for `&` and `'`, and
+/// percent-encoding for everything else.
+///
+pub(crate) fn decode_url_path(url: &str) -> String {
+    let url = url.replace("&amp;", "&").replace("&#x27;", "'");
+    let mut bytes = Vec::with_capacity(url.len());
+    let mut input = url.bytes();
+
+    while let Some(b) = input.next() {
+        if b == b'%' {
+            let hex: Vec<u8> = input.by_ref().take(2).collect();
+            let decoded = std::str::from_utf8(&hex).ok().
+                and_then(|h| u8::from_str_radix(h, 16).ok());
+
+            match decoded {
+                Some(d) => bytes.push(d),
+                None => {
+                    bytes.push(b);
+                    bytes.extend(hex);
+                },
+            }
+        } else {
+            bytes.push(b);
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
@@ -1,18 +1,29 @@
 //! Background monitoring for file changes.
 //!
 //! Whenever a file changes, we want to regenerate the HTML and send it to the UI for rendering to
-//! the user. This is done with the `init_update_loop` function.
+//! the user. This is done with the `init_update_loop` function. When a whole directory is open,
+//! `init_directory_loop` keeps an eye on documents appearing and disappearing in it. Commands from
+//! other processes come in through `init_control_loop`. Markdown streamed in through STDIN is
+//! rendered by `init_stream_loop`.
 
-use std::sync::mpsc;
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::thread;
 use std::time::Duration;
 use std::marker::Send;
+use std::path::{Path, PathBuf};
 
 use log::{debug, error, warn};
 use notify::{Watcher, RecursiveMode, DebouncedEvent, watcher};
 
-use crate::input::Config;
+use crate::control;
+use crate::input::{self, Config};
 use crate::markdown;
+use crate::render::Render;
 use crate::ui;
 
 /// A common trait for `glib::Sender` and `std::mpsc::Sender`.
@@ -40,20 +51,57 @@ impl Sender for mpsc::Sender<ui::Event> {
     }
 }
 
+/// A handle to a running update loop that can be used to stop it, for instance when a different
+/// file is opened in its place. Clones share the same loop.
+///
+#[derive(Debug, Clone, Default)]
+pub struct UpdateLoop {
+    stopped: Arc<AtomicBool>,
+}
+
+impl UpdateLoop {
+    /// Signal the background thread to stop watching and exit. It might take a moment to notice.
+    ///
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// True if `stop` has been called.
+    ///
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+}
+
 /// The main background worker. Spawns a thread and uses the `notify` crate to listen for file changes.
 ///
 /// Input:
 ///
-/// - `renderer`:  The struct that takes care of rendering the markdown file into HTML. Used to get
-///                the filename to monitor and to generate the HTML on update.
-/// - `ui_sender`: The channel to send `ui::Event` records to when a change is detected.
+/// - `renderer`:     Takes care of rendering the document into HTML. Used to get the filename to
+///                   monitor and to generate the HTML on update.
+/// - `local_images`: The local images in the document as it was last rendered, to watch for
+///                   changes.
+/// - `ui_sender`:    The channel to send `ui::Event` records to when a change is detected.
 ///
-/// A change to the main markdown file triggers a rerender and webview refresh. A change to the
-/// user-level configuration files is only going to trigger a refresh.
+/// A change to the main document triggers a rerender and webview refresh. A change to the
+/// user-level configuration files or to one of the local images in the document is only going to
+/// trigger a refresh. The images to watch are updated on every rerender, and directories that
+/// don't have any of them anymore are no longer watched.
 ///
-pub fn init_update_loop<S>(renderer: markdown::Renderer, mut ui_sender: S)
-    where S: Sender + Send + 'static
+/// The loop stops when the returned [`UpdateLoop`] is stopped, or if the receiving end of
+/// `ui_sender` has been dropped.
+///
+pub fn init_update_loop<R, S>(
+    renderer: R,
+    local_images: HashSet<PathBuf>,
+    mut ui_sender: S,
+) -> UpdateLoop
+    where R: Render + Send + 'static,
+          S: Sender + Send + 'static
 {
+    let update_loop = UpdateLoop::default();
+    let update_loop_clone = update_loop.clone();
+
     thread::spawn(move || {
         let (watcher_sender, watcher_receiver) = mpsc::channel();
 
@@ -65,9 +113,11 @@ pub fn init_update_loop<S>(renderer: markdown::Renderer, mut ui_sender: S)
             }
         };
 
+        let document_path = renderer.canonical_path().to_path_buf();
+
         // Watch the parent directory so we can catch recreated files
-        let main_watch_path = renderer.canonical_md_path.parent().
-            unwrap_or(&renderer.canonical_md_path).
+        let main_watch_path = document_path.parent().
+            unwrap_or(&document_path).
             to_owned();
 
         if let Err(e) = watcher.watch(&main_watch_path, RecursiveMode::NonRecursive) {
@@ -83,32 +133,343 @@ pub fn init_update_loop<S>(renderer: markdown::Renderer, mut ui_sender: S)
             extra_watch_paths.push(custom_css_path);
         }
 
+        let mut image_paths = local_images;
+        let mut image_dirs = HashSet::new();
+        watch_image_dirs(&mut watcher, &image_paths, &main_watch_path, &mut image_dirs);
+
         loop {
-            match watcher_receiver.recv() {
-                Ok(DebouncedEvent::Write(file) | DebouncedEvent::Create(file)) => {
+            let event = watcher_receiver.recv_timeout(Duration::from_millis(500));
+
+            if update_loop_clone.is_stopped() {
+                debug!("Stopped watching: {}", document_path.display());
+                return;
+            }
+
+            match event {
+                // Generated files, like images, are often moved into place
+                Ok(
+                    DebouncedEvent::Write(file) |
+                    DebouncedEvent::Create(file) |
+                    DebouncedEvent::Rename(_, file)
+                ) => {
                     debug!("File update/recreate detected: {}", file.display());
 
-                    if file == renderer.canonical_md_path {
-                        match renderer.run() {
-                            Ok(html) => {
-                                let _ = ui_sender.send(ui::Event::LoadHtml(html));
-                            },
-                            Err(e) => {
-                                error! {
-                                    "Error rendering markdown ({}): {:?}",
-                                    renderer.canonical_md_path.display(), e
-                                };
+                    let send_result =
+                        if file == document_path {
+                            match renderer.run() {
+                                Ok(html) => {
+                                    image_paths = html.local_images.clone();
+                                    watch_image_dirs(
+                                        &mut watcher,
+                                        &image_paths,
+                                        &main_watch_path,
+                                        &mut image_dirs,
+                                    );
+
+                                    ui_sender.send(ui::Event::LoadHtml(Box::new(html)))
+                                },
+                                Err(e) => {
+                                    error! {
+                                        "Error rendering document ({}): {:?}",
+                                        document_path.display(), e
+                                    };
+                                    Ok(())
+                                }
                             }
-                        }
-                    } else if extra_watch_paths.contains(&file) {
-                        let _ = ui_sender.send(ui::Event::Reload);
-                    } else {
-                        debug!("Ignored file update event: {:?}", file)
+                        } else if extra_watch_paths.contains(&file) || image_paths.contains(&file) {
+                            ui_sender.send(ui::Event::Reload)
+                        } else {
+                            debug!("Ignored file update event: {:?}", file);
+                            Ok(())
+                        };
+
+                    // The receiving end is gone, for instance if its tab was closed
+                    if send_result.is_err() {
+                        debug!("Stopped watching: {}", document_path.display());
+                        return;
                     }
                 },
                 Ok(event) => debug!("Ignored watcher event: {:?}", event),
-                Err(e) => error!("Error watching file for changes: {:?}", e),
+                Err(RecvTimeoutError::Timeout) => (),
+                Err(e) => {
+                    error!("Error watching file for changes: {:?}", e);
+                    return;
+                },
             }
         }
     });
+
+    update_loop
+}
+
+/// Watch the directories of the given images, rather than the files themselves, so that
+/// recreated files are caught too. The document's directory is always watched, so it's skipped.
+///
+/// The directories watched for the previous images are in `image_dirs`. The ones that don't have
+/// any of the images anymore are unwatched, and `image_dirs` is updated to the new set.
+///
+fn watch_image_dirs<W>(
+    watcher: &mut W,
+    images: &HashSet<PathBuf>,
+    document_dir: &Path,
+    image_dirs: &mut HashSet<PathBuf>,
+)
+    where W: Watcher
+{
+    let new_dirs: HashSet<PathBuf> = images.iter().
+        filter_map(|path| path.parent()).
+        filter(|dir| *dir != document_dir).
+        map(Path::to_path_buf).
+        collect();
+
+    for dir in image_dirs.difference(&new_dirs) {
+        match watcher.unwatch(dir) {
+            Ok(()) => debug!("Stopped watching images in {}", dir.display()),
+            Err(e) => debug!("Couldn't stop watching images in {}: {}", dir.display(), e),
+        }
+    }
+
+    for dir in new_dirs.difference(image_dirs) {
+        match watcher.watch(dir, RecursiveMode::NonRecursive) {
+            Ok(()) => debug!("Watching images in {}", dir.display()),
+            Err(e) => debug!("Couldn't watch images in {}: {}", dir.display(), e),
+        }
+    }
+
+    *image_dirs = new_dirs;
+}
+
+/// A background worker for directory mode. Spawns a thread that watches the entire tree under
+/// `root_dir` and sends a `ui::Event::RefreshFiles` to `ui_sender` whenever a document, or a
+/// directory with documents in it, is created, removed or renamed, so the list of files can be
+/// rebuilt. Changes in directories that [`input::find_documents`] skips are ignored.
+///
+/// Changes to the contents of files are not reported here, those are handled by the update loop
+/// of the currently rendered file.
+///
+/// The loop stops when the returned [`UpdateLoop`] is stopped, or if the receiving end of
+/// `ui_sender` has been dropped.
+///
+pub fn init_directory_loop<S>(root_dir: PathBuf, mut ui_sender: S) -> UpdateLoop
+    where S: Sender + Send + 'static
+{
+    let update_loop = UpdateLoop::default();
+    let update_loop_clone = update_loop.clone();
+
+    thread::spawn(move || {
+        let (watcher_sender, watcher_receiver) = mpsc::channel();
+
+        let mut watcher = match watcher(watcher_sender, Duration::from_millis(200)) {
+            Ok(w) => w,
+            Err(e) => {
+                warn!("Couldn't initialize watcher: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = watcher.watch(&root_dir, RecursiveMode::Recursive) {
+            warn!("Couldn't initialize watcher: {}", e);
+            return;
+        }
+
+        loop {
+            let event = watcher_receiver.recv_timeout(Duration::from_millis(500));
+
+            if update_loop_clone.is_stopped() {
+                debug!("Stopped watching: {}", root_dir.display());
+                return;
+            }
+
+            let changed = match event {
+                Ok(DebouncedEvent::Create(file) | DebouncedEvent::Remove(file)) => {
+                    affects_file_list(&root_dir, &file)
+                },
+                Ok(DebouncedEvent::Rename(old_file, new_file)) => {
+                    affects_file_list(&root_dir, &old_file) ||
+                        affects_file_list(&root_dir, &new_file)
+                },
+                Ok(event) => {
+                    debug!("Ignored watcher event: {:?}", event);
+                    false
+                },
+                Err(RecvTimeoutError::Timeout) => false,
+                Err(e) => {
+                    error!("Error watching directory for changes: {:?}", e);
+                    return;
+                },
+            };
+
+            if changed {
+                debug!("File list change detected in: {}", root_dir.display());
+
+                if ui_sender.send(ui::Event::RefreshFiles).is_err() {
+                    debug!("Stopped watching: {}", root_dir.display());
+                    return;
+                }
+            }
+        }
+    });
+
+    update_loop
+}
+
+/// A background worker for streamed input. Spawns a thread that reads snapshots of a markdown
+/// document from `reader`, separated by NUL bytes, and sends them to `ui_sender` as rendered HTML.
+/// Whatever comes after the last NUL byte is rendered when the stream ends.
+///
+/// Snapshots that come in while an earlier one is being rendered are out of date by the time it's
+/// done, so only the latest of them is rendered.
+///
+/// The loop stops when the returned [`UpdateLoop`] is stopped, or if the receiving end of
+/// `ui_sender` has been dropped. Reading a snapshot blocks, so that's only noticed once the next
+/// one arrives.
+///
+pub fn init_stream_loop<R, S>(
+    reader: R,
+    renderer: markdown::Renderer,
+    mut ui_sender: S,
+) -> UpdateLoop
+    where R: io::Read + Send + 'static,
+          S: Sender + Send + 'static
+{
+    let update_loop = UpdateLoop::default();
+    let update_loop_clone = update_loop.clone();
+
+    thread::spawn(move || {
+        // Reading in a thread of its own, so snapshots can pile up while rendering
+        let (snapshot_sender, snapshot_receiver) = mpsc::channel();
+        thread::spawn(move || read_snapshots(reader, snapshot_sender));
+
+        while let Ok(mut snapshot) = snapshot_receiver.recv() {
+            while let Ok(next_snapshot) = snapshot_receiver.try_recv() {
+                snapshot = next_snapshot;
+            }
+
+            if update_loop_clone.is_stopped() {
+                debug!("Stopped reading input stream");
+                return;
+            }
+
+            let contents = String::from_utf8_lossy(&snapshot);
+            let html = renderer.render(&contents);
+
+            // The receiving end is gone, for instance if its tab was closed
+            if ui_sender.send(ui::Event::LoadHtml(Box::new(html))).is_err() {
+                debug!("Stopped reading input stream");
+                return;
+            }
+        }
+
+        debug!("Input stream ended");
+    });
+
+    update_loop
+}
+
+/// Read NUL-separated snapshots from `reader` and send them to `snapshot_sender` until the
+/// stream ends, or until the receiving end is dropped.
+///
+fn read_snapshots<R: io::Read>(reader: R, snapshot_sender: mpsc::Sender<Vec<u8>>) {
+    let mut reader = BufReader::new(reader);
+
+    loop {
+        let mut snapshot = Vec::new();
+
+        match reader.read_until(b'\0', &mut snapshot) {
+            Ok(_) if snapshot.last() == Some(&b'\0') => {
+                snapshot.pop();
+            },
+            Ok(_) => {
+                if !snapshot.is_empty() {
+                    let _ = snapshot_sender.send(snapshot);
+                }
+                return;
+            },
+            Err(e) => {
+                error!("Error reading input stream: {:?}", e);
+                return;
+            },
+        }
+
+        if snapshot_sender.send(snapshot).is_err() {
+            return;
+        }
+    }
+}
+
+/// A background worker for the control socket. Spawns a thread that accepts connections on
+/// `listener` and sends the [`control::Command`] records that come in through them to
+/// `ui_sender` as `ui::Event` records. Each connection gets a thread of its own, so an editor can
+/// keep one open without blocking other clients.
+///
+/// The loop stops when the returned [`UpdateLoop`] is stopped. It's up to the caller to remove
+/// the socket file.
+///
+#[cfg(target_family="unix")]
+pub fn init_control_loop<S>(listener: std::os::unix::net::UnixListener, ui_sender: S) -> UpdateLoop
+    where S: Sender + Clone + Send + 'static
+{
+    let update_loop = UpdateLoop::default();
+    let update_loop_clone = update_loop.clone();
+
+    thread::spawn(move || {
+        // Polling, so we can notice when the loop is stopped
+        if let Err(e) = listener.set_nonblocking(true) {
+            warn!("Couldn't initialize control socket: {}", e);
+            return;
+        }
+
+        loop {
+            if update_loop_clone.is_stopped() {
+                debug!("Stopped listening for commands");
+                return;
+            }
+
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if let Err(e) = stream.set_nonblocking(false) {
+                        warn!("Couldn't read from control connection: {}", e);
+                        continue;
+                    }
+
+                    let mut ui_sender = ui_sender.clone();
+                    thread::spawn(move || {
+                        // The receiving end is gone, the app is probably shutting down
+                        if control::handle_connection(stream, &mut ui_sender).is_err() {
+                            debug!("Dropped control connection, UI is gone");
+                        }
+                    });
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(100));
+                },
+                Err(e) => {
+                    error!("Error listening for commands: {:?}", e);
+                    return;
+                },
+            }
+        }
+    });
+
+    update_loop
+}
+
+/// Documents are what's listed, so only they matter, along with directories that have documents in
+/// them. Nothing under a skipped directory, like `.git` or `target`, is listed at all. A removed
+/// path doesn't exist anymore, so we can't tell what it was, but a directory is unlikely to have
+/// an extension.
+///
+fn affects_file_list(root_dir: &Path, path: &Path) -> bool {
+    let relative_path = path.strip_prefix(root_dir).
+        unwrap_or_else(|_| Path::new(path.file_name().unwrap_or_default()));
+
+    if relative_path.iter().any(input::is_skipped_name) {
+        return false;
+    }
+
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => !input::find_documents(path).is_empty(),
+        Ok(_) => input::is_document_file(path),
+        Err(_) => input::is_document_file(path) || path.extension().is_none(),
+    }
 }
@@ -0,0 +1,277 @@
+//! Remote control of a running instance through a UNIX-domain socket.
+//!
+//! The app listens on a socket for commands, one JSON object per line, like:
+//!
+//! ```json
+//! {"command": "open", "path": "/home/user/project/README.md"}
+//! {"command": "scroll_to_line", "line": 42}
+//! {"command": "action", "action": "ZoomIn"}
+//! ```
+//!
+//! Commands are turned into [`ui::Event`] records and sent to the UI, the same way the background
+//! file watchers communicate with it. Once the UI has handled a command, it gets a JSON reply on a
+//! line of its own, either `{"ok":true}` or `{"ok":false,"error":"..."}`, like when a file to open
+//! doesn't exist. Running the app with `--remote` sends a single command and exits, and editor
+//! plugins can talk to the socket directly.
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SendError};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use directories::ProjectDirs;
+use log::debug;
+use serde::{Serialize, Deserialize};
+
+use crate::background::{self, Sender, UpdateLoop};
+use crate::ui::{self, action::Action};
+
+/// How long to wait for the UI to handle a command before replying with an error.
+///
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A command to a running instance of the app.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    /// Open the given file in a new tab, or switch to its tab if it's already open. Relative paths
+    /// are resolved against the working directory of the app, so it's best to send absolute ones.
+    Open {
+        /// The document to open
+        path: PathBuf,
+    },
+
+    /// Render the document in the current tab again and refresh the webview.
+    Reload,
+
+    /// Scroll the current tab to the given line of its document.
+    ScrollToLine {
+        /// A 1-based line number
+        line: usize,
+    },
+
+    /// Scroll the current tab to the element with the given id, like a heading's anchor.
+    ScrollToAnchor {
+        /// The id of the element, without a `#`
+        anchor: String,
+    },
+
+    /// Set the zoom level of the current tab, 1.0 being 100%.
+    SetZoom {
+        /// The zoom level
+        level: f64,
+    },
+
+    /// Perform an action that's usually mapped to a key. Only the ones that are triggered on key
+    /// release are supported, so no scrolling.
+    Action {
+        /// The action, named like in the `mappings` config setting
+        action: Action,
+    },
+
+    /// Quit the entire application.
+    Quit,
+}
+
+impl From<Command> for ui::Event {
+    fn from(command: Command) -> Self {
+        match command {
+            Command::Open { path }             => ui::Event::OpenFile(path),
+            Command::Reload                    => ui::Event::Reload,
+            Command::ScrollToLine { line }     => ui::Event::ScrollToLine(line),
+            Command::ScrollToAnchor { anchor } => ui::Event::ScrollToAnchor(anchor),
+            Command::SetZoom { level }         => ui::Event::SetZoom(level),
+            Command::Action { action }         => ui::Event::Action(action),
+            Command::Quit                      => ui::Event::Action(Action::Quit),
+        }
+    }
+}
+
+/// The socket used when none is given with `--socket`. Goes in the user's runtime directory,
+/// falling back to a file in the temp directory with the user's name in it.
+///
+pub fn default_socket_path() -> PathBuf {
+    ProjectDirs::from("com", "andrewradev", "quickmd").
+        and_then(|pd| pd.runtime_dir().map(|dir| dir.join("quickmd.sock"))).
+        unwrap_or_else(|| {
+            let user = env::var("USER").unwrap_or_default();
+            env::temp_dir().join(format!("quickmd-{}.sock", user))
+        })
+}
+
+/// A control socket that the app is listening on. The socket file is removed when the server is
+/// stopped.
+///
+#[derive(Debug)]
+pub struct ControlServer {
+    socket_path: PathBuf,
+    update_loop: UpdateLoop,
+}
+
+impl ControlServer {
+    /// Start listening on the given socket, sending every command that comes in to `ui_sender`.
+    ///
+    /// A leftover socket file from an instance that's not running anymore is replaced. Fails if
+    /// another instance is listening on the same socket, or if something other than a socket is
+    /// in the way.
+    ///
+    #[cfg(target_family="unix")]
+    pub fn start<S>(socket_path: PathBuf, ui_sender: S) -> anyhow::Result<Self>
+        where S: Sender + Clone + Send + 'static
+    {
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::UnixListener;
+
+        if let Ok(metadata) = fs::symlink_metadata(&socket_path) {
+            if !metadata.file_type().is_socket() {
+                let error = anyhow!("Not a socket, refusing to replace: {}", socket_path.display());
+                return Err(error);
+            }
+
+            if is_listening(&socket_path) {
+                let error = anyhow!("Another instance is listening on: {}", socket_path.display());
+                return Err(error);
+            }
+
+            debug!("Removing stale socket: {}", socket_path.display());
+            fs::remove_file(&socket_path)?;
+        }
+
+        if let Some(parent) = socket_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(&socket_path)?;
+        debug!("Listening for commands on: {}", socket_path.display());
+
+        let update_loop = background::init_control_loop(listener, ui_sender);
+        Ok(ControlServer { socket_path, update_loop })
+    }
+
+    /// Control sockets rely on UNIX-domain sockets, so this always fails.
+    ///
+    #[cfg(not(target_family="unix"))]
+    pub fn start<S>(_socket_path: PathBuf, _ui_sender: S) -> anyhow::Result<Self>
+        where S: Sender + Clone + Send + 'static
+    {
+        Err(anyhow!("Not on a UNIX system, can't listen on a control socket"))
+    }
+
+    /// The path of the socket that's listened on.
+    ///
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Stop accepting commands and remove the socket file.
+    ///
+    pub fn stop(&self) {
+        self.update_loop.stop();
+
+        if let Err(e) = fs::remove_file(&self.socket_path) {
+            debug!("Couldn't remove socket {}: {}", self.socket_path.display(), e);
+        }
+    }
+}
+
+/// True if there's an instance listening for commands on `socket_path`.
+///
+#[cfg(target_family="unix")]
+pub fn is_listening(socket_path: &Path) -> bool {
+    std::os::unix::net::UnixStream::connect(socket_path).is_ok()
+}
+
+/// Control sockets rely on UNIX-domain sockets, so there's never anything listening.
+///
+#[cfg(not(target_family="unix"))]
+pub fn is_listening(_socket_path: &Path) -> bool {
+    false
+}
+
+/// Send a command to the instance listening on `socket_path` and wait for its reply. Fails if
+/// there's no such instance or if it couldn't handle the command.
+///
+#[cfg(target_family="unix")]
+pub fn send(socket_path: &Path, command: &Command) -> anyhow::Result<()> {
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket_path).map_err(|e| {
+        anyhow!("Couldn't connect to a running instance on {}: {}", socket_path.display(), e)
+    })?;
+
+    writeln!(stream, "{}", serde_json::to_string(command)?)?;
+    stream.flush()?;
+
+    let mut reply = String::new();
+    BufReader::new(&stream).read_line(&mut reply)?;
+
+    let reply: serde_json::Value = serde_json::from_str(&reply)?;
+    if reply["ok"] == serde_json::Value::Bool(true) {
+        Ok(())
+    } else {
+        Err(anyhow!("Command failed: {}", reply["error"].as_str().unwrap_or("unknown error")))
+    }
+}
+
+/// Control sockets rely on UNIX-domain sockets, so this always fails.
+///
+#[cfg(not(target_family="unix"))]
+pub fn send(_socket_path: &Path, _command: &Command) -> anyhow::Result<()> {
+    Err(anyhow!("Not on a UNIX system, can't connect to a control socket"))
+}
+
+/// Read commands from a client connection until it's closed, replying to each of them with the
+/// result the UI sends back. Returns an error only if `ui_sender` fails, which means the UI is
+/// gone.
+///
+pub(crate) fn handle_connection<C, S>(stream: C, ui_sender: &mut S) -> Result<(), SendError<ui::Event>>
+    where C: io::Read + io::Write,
+          S: Sender
+{
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => return Ok(()),
+            Ok(_) => (),
+            Err(e) => {
+                debug!("Couldn't read control command: {}", e);
+                return Ok(());
+            },
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let reply = match serde_json::from_str::<Command>(&line) {
+            Ok(command) => {
+                debug!("Received control command: {:?}", command);
+
+                let (reply_sender, reply_receiver) = mpsc::channel();
+                ui_sender.send(ui::Event::Remote(Box::new(command.into()), reply_sender))?;
+
+                match reply_receiver.recv_timeout(REPLY_TIMEOUT) {
+                    Ok(Ok(())) => serde_json::json!({"ok": true}),
+                    Ok(Err(e)) => serde_json::json!({"ok": false, "error": e}),
+                    Err(e) => {
+                        let error = format!("No reply from the UI: {}", e);
+                        serde_json::json!({"ok": false, "error": error})
+                    },
+                }
+            },
+            Err(e) => serde_json::json!({"ok": false, "error": format!("Invalid command: {}", e)}),
+        };
+
+        let stream = reader.get_mut();
+        if writeln!(stream, "{}", reply).and_then(|_| stream.flush()).is_err() {
+            return Ok(());
+        }
+    }
+}
@@ -3,10 +3,14 @@
 //! This includes command-line options and settings from the YAML config. Potentially the place to
 //! handle any other type of configuration and input to the application.
 
-use std::fs::File;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs::{self, File};
 use std::io;
 use std::path::{PathBuf, Path};
+use std::process::Command;
 use std::rc::Rc;
+use std::sync::{LazyLock, Mutex};
 
 use anyhow::anyhow;
 use directories::ProjectDirs;
@@ -25,23 +29,62 @@ pub struct Options {
     #[structopt(short, long)]
     pub debug: bool,
 
-    /// Markdown file to render. Use "-" to read markdown from STDIN (implies --no-watch). If not
-    /// provided, the app will launch a file picker
+    /// Markdown, reStructuredText or AsciiDoc files to render, each one in its own tab. Use "-"
+    /// to read markdown from STDIN (implies --no-watch for it, see --stream for updates). A single
+    /// directory opens all documents in it with a sidebar to pick from. If not provided, the app
+    /// will launch a file picker
     #[structopt(name = "input-file.md", parse(from_os_str))]
-    pub input_file: Option<PathBuf>,
+    pub input_files: Vec<PathBuf>,
 
     /// Disables watching file for changes
     #[structopt(long = "no-watch", parse(from_flag = std::ops::Not::not))]
     pub watch: bool,
 
     /// Builds output HTML and other assets in the given directory instead of in a tempdir.
-    /// Will be created if it doesn't exist. Not deleted on application exit.
+    /// Will be created if it doesn't exist. Not deleted on application exit. With multiple input
+    /// files, each one gets a numbered subdirectory.
     #[structopt(long = "output", name = "directory")]
     pub output_dir: Option<PathBuf>,
 
+    /// Renders the markdown file into a single standalone HTML file with all styles, scripts and
+    /// local images embedded in it. Exits when done, without opening a window.
+    #[structopt(long = "export", name = "export-file.html", parse(from_os_str))]
+    pub export_file: Option<PathBuf>,
+
+    /// Prints the rendered markdown into a PDF file next to the markdown file. Exits when done,
+    /// without showing a window.
+    #[structopt(long)]
+    pub export_pdf: bool,
+
     /// Creates a configuration file for later editing if one doesn't exist. Exits when done.
     #[structopt(long)]
     pub install_default_config: bool,
+
+    /// Scrolls the preview to the given line of the markdown file once it's rendered. Meant for
+    /// launching the preview from an editor at the cursor's position
+    #[structopt(long = "line", name = "line")]
+    pub line: Option<usize>,
+
+    /// Sends a JSON command to a running instance instead of opening a window, for example
+    /// '{"command": "scroll_to_line", "line": 42}'. Exits when the command is handled, with an
+    /// error if it failed, like for a file that doesn't exist
+    #[structopt(long = "remote", name = "command")]
+    pub remote_command: Option<String>,
+
+    /// The control socket to listen on for commands, or to send commands to with --remote.
+    /// Defaults to quickmd.sock in the user's runtime directory
+    #[structopt(long = "socket", name = "socket", parse(from_os_str))]
+    pub socket_path: Option<PathBuf>,
+
+    /// If an instance is already running, opens the files in it instead of in a new window, or
+    /// switches to them if they're already open, and exits
+    #[structopt(long)]
+    pub single_instance: bool,
+
+    /// Reads STDIN as a stream of snapshots of the markdown document, separated by NUL bytes,
+    /// and rerenders with the latest one as they come in. Meant for editors to show unsaved changes
+    #[structopt(long)]
+    pub stream: bool,
 }
 
 impl Options {
@@ -109,6 +152,99 @@ pub struct Config {
 
     /// Custom mappings. See documentation of [`MappingDefinition`] for details.
     pub mappings: Vec<MappingDefinition>,
+
+    /// What to do with YAML or TOML front matter at the start of a document. It's never rendered
+    /// as markdown, but it can be shown as a table, which is what GitHub does.
+    ///
+    pub front_matter: FrontMatterDisplay,
+
+    /// Markdown extensions to enable on top of CommonMark. See [`MarkdownConfig`] for details.
+    pub markdown: MarkdownConfig,
+
+    /// The web URL of the repository documents belong to, like
+    /// `https://github.com/AndrewRadev/quickmd`. Issue references like `#123` and mentions like
+    /// `@user` are linked relative to it. If not set, it's detected from the `origin` git remote
+    /// of the document's directory.
+    ///
+    pub repository_url: Option<String>,
+
+    /// Strip scripts, iframes, event handlers and anything else GitHub wouldn't allow from the
+    /// rendered HTML. Off by default, since raw HTML in trusted documents can be useful, but it
+    /// should be turned on for previewing markdown from untrusted sources.
+    ///
+    pub sanitize_html: bool,
+}
+
+/// Toggles for the extensions to CommonMark that the markdown renderer supports. The ones that
+/// GitHub supports are on by default. Turning all of them off leaves just CommonMark syntax,
+/// though front matter is still cut off and headings still get ids.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarkdownConfig {
+    /// Tables with `|` separating columns.
+    pub tables: bool,
+
+    /// Footnote references like `[^1]` and their definitions.
+    pub footnotes: bool,
+
+    /// Struck-through text between `~~` markers.
+    pub strikethrough: bool,
+
+    /// List items that start with checkboxes, `[ ]` or `[x]`.
+    pub tasklists: bool,
+
+    /// Typographic quotes, dashes and ellipses instead of their ASCII equivalents.
+    pub smart_punctuation: bool,
+
+    /// Ids and classes for headings written after them, like `# Heading {#id .class}`.
+    pub heading_attributes: bool,
+
+    /// Blockquotes starting with a marker like `[!NOTE]` shown as GitHub-style alerts.
+    pub alerts: bool,
+
+    /// Math between dollar signs, `$inline$` or `$$display$$`, typeset instead of parsed.
+    pub math: bool,
+
+    /// Code blocks in the `mermaid` language drawn as diagrams.
+    pub diagrams: bool,
+
+    /// Links for bare URLs, issue references like `#123` and mentions like `@user`.
+    pub autolinks: bool,
+
+    /// Emoji shortcodes like `:tada:` replaced with the emoji.
+    pub emoji: bool,
+}
+
+impl Default for MarkdownConfig {
+    fn default() -> Self {
+        Self {
+            tables:             true,
+            footnotes:          true,
+            strikethrough:      true,
+            tasklists:          true,
+            smart_punctuation:  false,
+            heading_attributes: false,
+            alerts:             true,
+            math:               true,
+            diagrams:           true,
+            autolinks:          true,
+            emoji:              true,
+        }
+    }
+}
+
+/// The ways front matter in a document can be displayed.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrontMatterDisplay {
+    /// Show a table of its fields at the top of the page.
+    #[default]
+    Table,
+
+    /// Don't show it at all.
+    Hidden,
 }
 
 /// A single description of a mapping from a keybinding to a UI action. The fields `key_char` and
@@ -132,7 +268,15 @@ pub struct MappingDefinition {
 
 impl Default for Config {
     fn default() -> Self {
-        Self { zoom: 1.0, editor_command: Vec::new(), mappings: Vec::new() }
+        Self {
+            zoom:           1.0,
+            editor_command: Vec::new(),
+            mappings:       Vec::new(),
+            front_matter:   FrontMatterDisplay::default(),
+            markdown:       MarkdownConfig::default(),
+            repository_url: None,
+            sanitize_html:  false,
+        }
     }
 }
 
@@ -194,6 +338,10 @@ pub enum InputFile {
     /// STDIN, written to a named tempfile. It's packaged in an Rc, so we can safely clone the
     /// structure.
     Stdin(Rc<NamedTempFile>),
+
+    /// STDIN, read as a stream of snapshots of the document. There's no actual file, the path is
+    /// a stand-in in the current directory, so relative links and images are resolved against it.
+    StdinStream(PathBuf),
 }
 
 impl InputFile {
@@ -213,16 +361,200 @@ impl InputFile {
         }
     }
 
-    /// Get the path to a real file on the filesystem.
+    /// Construct an `InputFile` for STDIN that's read continuously. Nothing is read until the
+    /// stream is rendered.
+    ///
+    pub fn stdin_stream() -> anyhow::Result<InputFile> {
+        Ok(InputFile::StdinStream(std::env::current_dir()?.join("STDIN")))
+    }
+
+    /// Get the path to a real file on the filesystem. For a stream, the file doesn't exist.
     pub fn path(&self) -> &Path {
         match self {
-            Self::Filesystem(path_buf) => path_buf.as_path(),
-            Self::Stdin(tempfile)      => tempfile.path(),
+            Self::Filesystem(path_buf)  => path_buf.as_path(),
+            Self::Stdin(tempfile)       => tempfile.path(),
+            Self::StdinStream(path_buf) => path_buf.as_path(),
         }
     }
 
+    /// True if the struct represents a stream of snapshots from STDIN.
+    pub fn is_stream(&self) -> bool {
+        matches!(self, Self::StdinStream(_))
+    }
+
     /// Only true if the struct represents an actual file.
     pub fn is_real_file(&self) -> bool {
         matches!(self, Self::Filesystem(_))
     }
 }
+
+/// Extensions of the files we consider to be markdown.
+///
+pub const MARKDOWN_EXTENSIONS: &[&str] = &["md", "markdown"];
+
+/// Extensions of the files we consider to be reStructuredText.
+///
+pub const RST_EXTENSIONS: &[&str] = &["rst", "rest"];
+
+/// Extensions of the files we consider to be AsciiDoc.
+///
+pub const ASCIIDOC_EXTENSIONS: &[&str] = &["adoc", "asciidoc"];
+
+/// The formats of documents the app can render. Each one has its own renderer, picked by the
+/// extension of the file. The file picker and the directory sidebar only show files in one of
+/// these formats.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// Files with one of the [`MARKDOWN_EXTENSIONS`].
+    Markdown,
+
+    /// Files with one of the [`RST_EXTENSIONS`].
+    Rst,
+
+    /// Files with one of the [`ASCIIDOC_EXTENSIONS`].
+    AsciiDoc,
+}
+
+impl DocumentFormat {
+    /// Find the format of the given file by its extension, ignoring case. Returns `None` if it's
+    /// not one of the known ones.
+    ///
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        if MARKDOWN_EXTENSIONS.contains(&extension.as_str()) {
+            Some(DocumentFormat::Markdown)
+        } else if RST_EXTENSIONS.contains(&extension.as_str()) {
+            Some(DocumentFormat::Rst)
+        } else if ASCIIDOC_EXTENSIONS.contains(&extension.as_str()) {
+            Some(DocumentFormat::AsciiDoc)
+        } else {
+            None
+        }
+    }
+}
+
+/// True if the path is a document in one of the known [`DocumentFormat`]s.
+///
+pub fn is_document_file(path: &Path) -> bool {
+    DocumentFormat::from_path(path).is_some()
+}
+
+/// Directories with build output or dependencies in them, rather than documentation. They're
+/// skipped when looking for documents.
+///
+pub const SKIPPED_DIRECTORIES: &[&str] = &["target", "node_modules", "_build", "build", "dist"];
+
+/// True if nothing in the file or directory with the given name is listed as a document: hidden
+/// entries (like `.git`) and the [`SKIPPED_DIRECTORIES`].
+///
+pub fn is_skipped_name(name: &OsStr) -> bool {
+    let name = name.to_string_lossy();
+    name.starts_with('.') || SKIPPED_DIRECTORIES.contains(&name.as_ref())
+}
+
+/// Recursively collect all documents under the given directory, sorted by path. Entries with
+/// [skipped names](is_skipped_name) are left out, and so are entries that can't be read.
+/// Symlinked directories are not followed, since they could lead out of the tree, or in a loop.
+///
+pub fn find_documents(root_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_documents(root_dir, &mut files);
+    files.sort();
+    files
+}
+
+fn collect_documents(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!("Couldn't read directory {}: {}", dir.display(), e);
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if is_skipped_name(&entry.file_name()) {
+            continue;
+        }
+
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(e) => {
+                debug!("Couldn't read file type of {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        if file_type.is_dir() {
+            collect_documents(&path, files);
+        } else if is_document_file(&path) && path.is_file() {
+            files.push(path);
+        }
+    }
+}
+
+/// Find the web URL of the repository the given directory is in, based on its `origin` git
+/// remote. Returns `None` if it's not in a repository, or if git can't be run.
+///
+/// The result is cached for each directory, since every rerender of a document asks for it again.
+///
+pub fn detect_repository_url(dir: &Path) -> Option<String> {
+    static CACHE: LazyLock<Mutex<HashMap<PathBuf, Option<String>>>> =
+        LazyLock::new(Default::default);
+
+    // [Unwrap] The lock is only held for lookups and inserts, which don't panic
+    if let Some(url) = CACHE.lock().unwrap().get(dir) {
+        return url.clone();
+    }
+
+    let url = find_repository_url(dir);
+    // [Unwrap] The lock is only held for lookups and inserts, which don't panic
+    CACHE.lock().unwrap().insert(dir.to_path_buf(), url.clone());
+    url
+}
+
+fn find_repository_url(dir: &Path) -> Option<String> {
+    let output = Command::new("git").
+        arg("-C").arg(dir).
+        args(["remote", "get-url", "origin"]).
+        output().
+        map_err(|e| debug!("Couldn't run git: {}", e)).
+        ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    repository_url_from_remote(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+/// Turn a git remote into the web URL of its repository. Supports HTTP(S) remotes and SSH ones
+/// in both the `git@host:org/repo.git` and `ssh://git@host/org/repo.git` forms.
+///
+pub fn repository_url_from_remote(remote: &str) -> Option<String> {
+    let remote = remote.trim_end_matches('/');
+    let remote = remote.strip_suffix(".git").unwrap_or(remote);
+
+    let (host, path) =
+        if let Some(rest) = remote.strip_prefix("https://").or_else(|| remote.strip_prefix("http://")) {
+            rest.split_once('/')?
+        } else if let Some(rest) = remote.strip_prefix("ssh://") {
+            rest.split_once('/')?
+        } else {
+            remote.split_once(':')?
+        };
+
+    // Drop any user name and port
+    let host = host.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+
+    if host.is_empty() || path.is_empty() {
+        return None;
+    }
+
+    Some(format!("https://{}/{}", host, path))
+}
@@ -4,8 +4,12 @@
 //! used as a library, but the individual components are there, and theoretically usable. Check the
 //! `README.md` file for instructions on how to use it as an app.
 
+pub mod asciidoc;
 pub mod assets;
 pub mod background;
+pub mod control;
 pub mod input;
 pub mod markdown;
+pub mod render;
+pub mod rst;
 pub mod ui;
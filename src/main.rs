@@ -3,12 +3,12 @@ use std::process;
 use std::path::{Path, PathBuf};
 
 use anyhow::anyhow;
-use log::debug;
+use log::{debug, warn};
 
 use quickmd::assets::Assets;
-use quickmd::background;
-use quickmd::input::{Config, Options, InputFile};
-use quickmd::markdown::Renderer;
+use quickmd::control::{self, Command};
+use quickmd::input::{self, Config, Options, InputFile};
+use quickmd::render::{self, Render};
 use quickmd::ui;
 
 fn main() {
@@ -33,13 +33,29 @@ fn run(config: &Config, options: &Options) -> anyhow::Result<()> {
         return Config::try_install_default();
     }
 
+    if let Some(remote_command) = options.remote_command.as_ref() {
+        return remote(remote_command, options);
+    }
+
+    if let Some(export_file) = options.export_file.as_ref() {
+        return match options.input_files.as_slice() {
+            [input_file] => export(input_file, export_file, config),
+            [] => Err(anyhow!("Please provide a markdown file to export")),
+            _  => Err(anyhow!("Please provide a single markdown file to export")),
+        };
+    }
+
+    if options.single_instance && !options.export_pdf && hand_over(options)? {
+        return Ok(());
+    }
+
     gtk::init()?;
 
-    if let Some(input_file) = options.input_file.as_ref() {
-        launch_app(input_file, options, config)
-    } else {
+    if options.input_files.is_empty() {
         let input_file = launch_file_picker()?;
-        launch_app(&input_file, options, config)
+        launch_app(&[input_file], options, config)
+    } else {
+        launch_app(&options.input_files, options, config)
     }
 }
 
@@ -49,30 +65,138 @@ fn launch_file_picker() -> anyhow::Result<PathBuf> {
     })
 }
 
-fn launch_app(input_file: &Path, options: &Options, config: &Config) -> anyhow::Result<()> {
-    let input_file   = InputFile::from(input_file, io::stdin())?;
-    let is_real_file = input_file.is_real_file();
-    let md_path      = input_file.path();
+fn export(input_file: &Path, export_file: &Path, config: &Config) -> anyhow::Result<()> {
+    let input_file = InputFile::from(input_file, io::stdin())?;
+    let md_path    = input_file.path();
 
     if !md_path.exists() {
         let error = anyhow!("File not found: {}", md_path.display());
         return Err(error);
     }
 
-    let renderer = Renderer::new(md_path.to_path_buf());
-    let assets = Assets::init(options.output_dir.clone())?;
+    // STDIN is written to a temporary file, its name wouldn't mean anything
+    let title = match md_path.file_name() {
+        Some(file_name) if input_file.is_real_file() => file_name.to_string_lossy().into_owned(),
+        _ => String::from("Quickmd"),
+    };
 
-    let mut ui = ui::App::init(config.clone(), input_file.clone(), assets)?;
-    let (ui_sender, ui_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
-    ui.init_render_loop(ui_receiver);
+    let renderer = render::export_renderer_for(md_path.to_path_buf(), config);
+    Assets::build_standalone(&renderer.run()?, &title, export_file)
+}
 
-    // Initial render
-    ui_sender.send(ui::Event::LoadHtml(renderer.run()?))?;
+fn remote(remote_command: &str, options: &Options) -> anyhow::Result<()> {
+    let mut command: Command = serde_json::from_str(remote_command).
+        map_err(|e| anyhow!("Invalid command: {}", e))?;
 
-    if is_real_file && options.watch {
-        background::init_update_loop(renderer, ui_sender);
+    // The running instance might have a different working directory
+    if let Command::Open { path } = &mut command {
+        *path = std::env::current_dir()?.join(path.as_path());
     }
 
+    control::send(&socket_path(options), &command)
+}
+
+/// Open the input files in an instance that's already running, if there is one. Returns false if
+/// they need to be opened here instead. STDIN and directories are always opened here.
+///
+fn hand_over(options: &Options) -> anyhow::Result<bool> {
+    let socket_path = socket_path(options);
+    let is_file = |path: &PathBuf| path != Path::new("-") && !path.is_dir();
+
+    if options.input_files.is_empty() ||
+        !options.input_files.iter().all(is_file) ||
+        !control::is_listening(&socket_path) {
+        return Ok(false);
+    }
+
+    let current_dir = std::env::current_dir()?;
+
+    for input_file in &options.input_files {
+        let path = current_dir.join(input_file);
+        if !path.exists() {
+            return Err(anyhow!("File not found: {}", path.display()));
+        }
+
+        debug!("Handing over to running instance: {}", path.display());
+        control::send(&socket_path, &Command::Open { path })?;
+    }
+
+    if let Some(line) = options.line {
+        control::send(&socket_path, &Command::ScrollToLine { line })?;
+    }
+
+    Ok(true)
+}
+
+fn socket_path(options: &Options) -> PathBuf {
+    options.socket_path.clone().unwrap_or_else(control::default_socket_path)
+}
+
+fn launch_app(input_files: &[PathBuf], options: &Options, config: &Config) -> anyhow::Result<()> {
+    let mut ui = ui::App::init(config.clone())?;
+
+    match input_files {
+        [root_dir] if root_dir.is_dir() => open_directory(&ui, root_dir, options)?,
+        _ => {
+            for (index, input_file) in input_files.iter().enumerate() {
+                if input_file.is_dir() {
+                    let error = anyhow!("A directory can only be opened on its own: {}", input_file.display());
+                    return Err(error);
+                }
+
+                let output_dir = options.output_dir.as_ref().map(|dir| {
+                    if input_files.len() > 1 { dir.join(index.to_string()) } else { dir.clone() }
+                });
+
+                open_tab(&ui, input_file, output_dir, options)?;
+            }
+        },
+    }
+
+    if options.export_pdf {
+        ui.run_pdf_export();
+        return Ok(());
+    }
+
+    ui.listen(socket_path(options), options.watch).
+        unwrap_or_else(|e| warn!("Couldn't listen for commands: {}", e));
+
     ui.run();
     Ok(())
 }
+
+fn open_directory(ui: &ui::App, root_dir: &Path, options: &Options) -> anyhow::Result<()> {
+    let files = input::find_documents(root_dir);
+
+    // Start with the directory's README if there is one, or the first file in the tree otherwise
+    let is_readme = |path: &&PathBuf| {
+        path.parent() == Some(root_dir) &&
+            path.file_stem().is_some_and(|s| s.eq_ignore_ascii_case("readme"))
+    };
+    let start_file = files.iter().find(is_readme).or_else(|| files.first()).
+        ok_or_else(|| anyhow!("No documents found in: {}", root_dir.display()))?;
+
+    open_tab(ui, start_file, options.output_dir.clone(), options)?;
+    ui.add_sidebar(root_dir.to_path_buf(), options.watch && !options.export_pdf);
+
+    Ok(())
+}
+
+fn open_tab(ui: &ui::App, input_file: &Path, output_dir: Option<PathBuf>, options: &Options) -> anyhow::Result<()> {
+    let input_file =
+        if options.stream && input_file == Path::new("-") {
+            InputFile::stdin_stream()?
+        } else {
+            InputFile::from(input_file, io::stdin())?
+        };
+
+    let assets     = Assets::init(output_dir)?;
+    let watch      = options.watch && !options.export_pdf;
+
+    let tab = ui.add_tab(input_file, assets, watch)?;
+    if let Some(line) = options.line {
+        tab.scroll_to_line(line);
+    }
+
+    Ok(())
+}
@@ -1,16 +1,93 @@
 //! Markdown rendering.
 //!
-//! Uses the [`pulldown_cmark`] crate with Github-flavored markdown options enabled. Extracts
-//! languages used in code blocks for highlighting purposes.
+//! Uses the [`pulldown_cmark`] crate with Github-flavored markdown options enabled. Code blocks in
+//! languages that [`syntect`] knows about are highlighted directly into the HTML. Extracts
+//! languages used in code blocks, so the rest can be highlighted on the client side, and the
+//! headings of the document, so they can be shown as an outline. Headings get ids the same way
+//! GitHub generates them, so links to them work like they do there. Blockquotes starting with
+//! a marker like `[!NOTE]` are rendered as GitHub-style alerts, and math between dollar signs
+//! is marked up separately from the surrounding text.
+//!
+//! Bare URLs are turned into links, and so are issue references and user mentions, if the
+//! document is in a known repository. Emoji shortcodes like `:tada:` are replaced with the emoji
+//! themselves.
+//!
+//! Block elements can be given a `data-source-line` attribute with the line of the markdown they
+//! come from, so the preview can be scrolled to the part of the document that's being edited.
+//!
+//! With the `sanitize_html` config setting, the HTML is cleaned up afterwards, keeping only the
+//! elements and attributes that GitHub allows.
+//!
+//! YAML or TOML front matter at the start of a file is not treated as markdown. It's parsed into
+//! [`RenderedContent::front_matter`] and optionally shown as a table.
 
 use std::fs;
 use std::io;
 use std::path::{PathBuf, Path};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 use regex::Regex;
+use log::warn;
+use serde_json::Value;
 use pulldown_cmark::{Parser, Options, Event, html};
+use pulldown_cmark::escape::escape_html;
+use syntect::html::{ClassedHTMLGenerator, ClassStyle};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+use crate::assets;
+use crate::input::{self, Config, FrontMatterDisplay, MarkdownConfig};
+use crate::render::Render;
+
+/// The prefix of the CSS classes of highlighted code. The styles for them are in
+/// `res/style/syntax-github.css`.
+///
+const SYNTAX_CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "syn-" };
+
+/// The language of fenced code blocks that contain diagram definitions instead of code.
+///
+const DIAGRAM_LANGUAGE: &str = "mermaid";
+
+/// The syntaxes that code blocks are highlighted with. Loading them takes a while, so it's done
+/// once, the first time they're needed, and shared by all renderers.
+///
+pub(crate) static SYNTAX_SET: LazyLock<SyntaxSet> =
+    LazyLock::new(SyntaxSet::load_defaults_newlines);
 
-/// Encapsulates a markdown file and provides an interface to turn its contents into HTML.
+/// The elements that are kept in sanitized HTML. It's the list GitHub uses, plus the checkboxes
+/// of task lists.
+///
+const SANITIZED_TAGS: &[&str] = &[
+    "h1", "h2", "h3", "h4", "h5", "h6", "h7", "h8", "br", "b", "i", "strong", "em", "a", "pre",
+    "code", "img", "tt", "div", "ins", "del", "sup", "sub", "p", "ol", "ul", "table", "thead",
+    "tbody", "tfoot", "blockquote", "dl", "dt", "dd", "kbd", "q", "samp", "var", "hr", "ruby", "rt",
+    "rp", "li", "tr", "td", "th", "s", "strike", "summary", "details", "caption", "figure",
+    "figcaption", "abbr", "bdo", "cite", "dfn", "mark", "small", "span", "time", "wbr", "input",
+];
+
+/// The attributes that are kept on any element in sanitized HTML. GitHub's list, plus `id`,
+/// `class` and `data-source-line`, which the renderer uses for headings, highlighting, alerts,
+/// math and source lines. Ids and names are prefixed with [`USER_CONTENT_PREFIX`].
+///
+const SANITIZED_ATTRIBUTES: &[&str] = &[
+    "abbr", "accept", "accept-charset", "accesskey", "action", "align", "alt", "aria-describedby",
+    "aria-hidden", "aria-label", "aria-labelledby", "axis", "border", "cellpadding", "cellspacing",
+    "char", "charoff", "charset", "checked", "class", "clear", "cols", "colspan", "color",
+    "compact", "coords", "datetime", "dir", "disabled", "enctype", "for", "frame", "headers",
+    "height", "hreflang", "hspace", "id", "ismap", "itemprop", "label", "lang", "maxlength",
+    "media", "method", "multiple", "name", "nohref", "noshade", "nowrap", "open", "progress",
+    "prompt", "readonly", "rel", "rev", "role", "rows", "rowspan", "rules", "scope", "selected",
+    "shape", "size", "span", "start", "summary", "tabindex", "target", "title", "type", "usemap",
+    "valign", "value", "vspace", "width", "data-source-line",
+];
+
+/// The prefix of ids and names in sanitized HTML, the same one GitHub uses, so the document can't
+/// take over the ids that the page's own scripts look for.
+///
+const USER_CONTENT_PREFIX: &str = "user-content-";
+
+/// Encapsulates a markdown file and provides an interface to turn its contents into HTML, using the
+/// markdown extensions and front matter settings from the config.
 ///
 pub struct Renderer {
     /// The original path given to the renderer.
@@ -18,63 +95,1130 @@ pub struct Renderer {
 
     /// The canonicalized path to use in file operations.
     pub canonical_md_path: PathBuf,
+
+    front_matter_display: FrontMatterDisplay,
+    extensions: MarkdownConfig,
+    options: Options,
+    autolinker: Autolinker,
+    sanitize_html: bool,
+    source_lines: bool,
+    resolve_links: bool,
 }
 
 impl Renderer {
     /// Create a new renderer instance that wraps the given markdown file.
     ///
-    pub fn new(md_path: PathBuf) -> Self {
+    pub fn new(md_path: PathBuf, config: &Config) -> Self {
         let canonical_md_path = md_path.canonicalize().
             unwrap_or_else(|_| md_path.clone());
 
-        Renderer { md_path, canonical_md_path }
+        let front_matter_display = config.front_matter;
+        let extensions = config.markdown.clone();
+        let options = parser_options(&extensions);
+
+        let repository_url = config.repository_url.clone().or_else(|| {
+            // No need to look for a repository if nothing is going to be linked to it
+            let dir = canonical_md_path.parent().filter(|_| extensions.autolinks)?;
+            input::detect_repository_url(dir)
+        });
+        let autolinker = Autolinker::new(repository_url.as_deref());
+        let sanitize_html = config.sanitize_html;
+
+        Renderer {
+            md_path, canonical_md_path, front_matter_display, extensions, options, autolinker,
+            sanitize_html,
+            source_lines: false,
+            resolve_links: true,
+        }
+    }
+
+    /// Give block elements a `data-source-line` attribute with the (1-based) line of the markdown
+    /// file they start on: paragraphs, headings, blockquotes, lists and their items, code blocks
+    /// and horizontal rules.
+    ///
+    pub fn with_source_lines(mut self) -> Self {
+        self.source_lines = true;
+        self
+    }
+
+    /// Keep relative links as they're written, rather than turning them into `file://` URLs in the
+    /// document's directory for the webview. That's what a standalone export needs, since it could
+    /// be read anywhere. Local images are still resolved, so they can be inlined.
+    ///
+    pub fn with_relative_links(mut self) -> Self {
+        self.resolve_links = false;
+        self
     }
 
     /// Turn the current contents of the markdown file into HTML.
     ///
     pub fn run(&self) -> Result<RenderedContent, io::Error> {
-        let markdown = fs::read_to_string(&self.canonical_md_path)?;
+        let contents = fs::read_to_string(&self.canonical_md_path)?;
+        Ok(self.render(&contents))
+    }
+
+    /// Turn the given markdown into HTML, as if it was the contents of the markdown file. Local
+    /// links and images are still resolved against the file's directory.
+    ///
+    pub fn render(&self, contents: &str) -> RenderedContent {
+        let (front_matter, markdown) = split_front_matter(contents);
         let root_dir = self.canonical_md_path.parent().unwrap_or_else(|| Path::new(""));
 
-        let re_absolute_url = Regex::new(r"^[a-z]+://").unwrap();
+        let re_url_scheme = Regex::new(r"^[a-zA-Z][a-zA-Z\d+.-]*:").unwrap();
         let re_path_prefix = Regex::new(r"^(/|\./)?").unwrap();
 
-        let mut options = Options::empty();
-        options.insert(Options::ENABLE_TABLES);
-        options.insert(Options::ENABLE_FOOTNOTES);
-        options.insert(Options::ENABLE_TASKLISTS);
-        options.insert(Options::ENABLE_STRIKETHROUGH);
-        let parser = Parser::new_ext(&markdown, options);
+        // Front matter is cut off, but it's still part of the file
+        let first_line = contents[..contents.len() - markdown.len()].matches('\n').count();
+
+        let (markdown, math) =
+            if self.extensions.math {
+                Math::extract(markdown, self.options)
+            } else {
+                (markdown.to_string(), Math::new())
+            };
+        let parser = Parser::new_ext(&markdown, self.options);
+        let source_lines = self.source_lines.then(|| SourceLines::new(first_line, &markdown, &math));
 
         let mut languages = HashSet::new();
-        let parser = parser.map(|mut event| {
-            use pulldown_cmark::{Tag, CodeBlockKind};
+        let mut code_block: Option<CodeBlock> = None;
+        let mut diagram: Option<String> = None;
+        let mut has_diagrams = false;
+        let mut headings = Vec::new();
+        let mut heading: Option<Heading> = None;
+        let mut heading_start = 0;
+        let mut slugger = Slugger::new();
+        let mut local_images = HashSet::new();
+        let mut events = Vec::new();
+
+        for (mut event, range) in parser.into_offset_iter() {
+            use pulldown_cmark::{Tag, CodeBlockKind, LinkType};
+
+            if let Some(source_lines) = source_lines.as_ref().filter(|_| is_annotated_block(&event)) {
+                events.push(source_line_marker(source_lines.line(range.start)));
+            }
 
             match &mut event {
                 Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(content))) => {
+                    if self.extensions.diagrams &&
+                        content.split_whitespace().next() == Some(DIAGRAM_LANGUAGE) {
+                        // Not code, so no highlighting, the contents are rendered as they are
+                        diagram = Some(String::new());
+                        continue;
+                    }
+
                     if content.len() > 0 {
                         languages.insert(content.to_string());
+
+                        // Swallow the opening tag if we can highlight the contents, they'll be
+                        // rendered together on the closing tag
+                        code_block = self.find_syntax(content).map(|syntax| CodeBlock::new(content, syntax));
+                        if code_block.is_some() {
+                            continue;
+                        }
+                    }
+                },
+                Event::Text(text) => {
+                    if let Some(diagram) = diagram.as_mut() {
+                        diagram.push_str(text);
+                        continue;
+                    }
+                    if let Some(code_block) = code_block.as_mut() {
+                        code_block.code.push_str(text);
+                        continue;
+                    }
+                    if let Some(heading) = heading.as_mut() {
+                        heading.text.push_str(&math.restore(text));
                     }
                 },
-                Event::Start(Tag::Image(_, url, _)) if !re_absolute_url.is_match(url) => {
+                Event::Code(text) => {
+                    if let Some(heading) = heading.as_mut() {
+                        heading.text.push_str(text);
+                    }
+                },
+                Event::Start(Tag::Heading(level, _, _)) => {
+                    heading = Some(Heading { level: *level as u32, id: String::new(), text: String::new() });
+                    heading_start = events.len();
+                },
+                Event::End(Tag::Heading(..)) => {
+                    if let Some(mut heading) = heading.take() {
+                        // The id depends on the text of the heading, so the opening tag is only
+                        // written down once we have all of it
+                        if let Event::Start(Tag::Heading(_, id, classes)) = &events[heading_start] {
+                            heading.id = match id {
+                                Some(id) => slugger.reserve(id),
+                                None     => slugger.slug(&heading.text),
+                            };
+                            events[heading_start] = Event::Html(heading.opening_tag(classes).into());
+                        }
+                        headings.push(heading);
+                    }
+                },
+                Event::End(Tag::CodeBlock(_)) => {
+                    if let Some(source) = diagram.take() {
+                        events.push(Event::Html(render_diagram(&source).into()));
+                        has_diagrams = true;
+                        continue;
+                    }
+                    if let Some(code_block) = code_block.take() {
+                        events.push(Event::Html(code_block.highlight(&SYNTAX_SET).into()));
+                        continue;
+                    }
+                },
+                Event::Start(Tag::Image(_, url, _)) if !re_url_scheme.is_match(url) => {
+                    let path = re_path_prefix.replace(url, "").into_owned();
+                    local_images.insert(local_file_path(root_dir, &path));
+                    *url = format!("file://{}/{}", root_dir.display(), path).into();
+                },
+                // Fragment-only links point within the page, and emails get a scheme when rendered
+                Event::Start(Tag::Link(link_type, url, _))
+                    if self.resolve_links && *link_type != LinkType::Email &&
+                        !url.starts_with('#') && !re_url_scheme.is_match(url) => {
                     *url = format!("file://{}/{}", root_dir.display(), re_path_prefix.replace(url, "")).into();
                 },
                 _ => (),
             }
 
-            event
-        });
+            events.push(event);
+        }
+
+        let front_matter = front_matter.and_then(|f| f.parse());
 
         let mut output = String::new();
-        html::push_html(&mut output, parser);
+        if let (Some(data), FrontMatterDisplay::Table) = (&front_matter, self.front_matter_display) {
+            output.push_str(r#"<table class="front-matter">"#);
+            render_front_matter_table(&mut output, data);
+            output.push_str("</table>\n");
+        }
+        let events = if self.extensions.alerts { render_alerts(events) } else { events };
+        let events = if self.extensions.autolinks { self.autolinker.run(events) } else { events };
+        let events = if self.extensions.emoji { render_emoji(events) } else { events };
+        let events = math.render(events);
 
-        Ok(RenderedContent {
+        if source_lines.is_some() {
+            html::push_html(&mut output, render_source_lines(events).into_iter());
+        } else {
+            html::push_html(&mut output, events.into_iter());
+        }
+
+        if self.sanitize_html {
+            output = sanitize_html(&output);
+        }
+
+        RenderedContent {
             html: output,
             code_languages: languages,
-        })
+            headings,
+            front_matter,
+            local_images,
+            has_diagrams,
+            has_math: !math.expressions.is_empty(),
+        }
+    }
+
+    fn find_syntax(&self, language: &str) -> Option<&SyntaxReference> {
+        let token = language.split_whitespace().next()?;
+        SYNTAX_SET.find_syntax_by_token(token)
+    }
+}
+
+impl Render for Renderer {
+    fn canonical_path(&self) -> &Path {
+        &self.canonical_md_path
+    }
+
+    fn run(&self) -> Result<RenderedContent, io::Error> {
+        Renderer::run(self)
+    }
+}
+
+/// The file that a relative `path` from a document in `root_dir` points to, without any query or
+/// fragment, and with percent-encoding undone. Canonicalized if it exists, so it matches the paths
+/// that file watchers report.
+///
+pub(crate) fn local_file_path(root_dir: &Path, path: &str) -> PathBuf {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let path = root_dir.join(assets::decode_url_path(path));
+
+    path.canonicalize().unwrap_or(path)
+}
+
+fn parser_options(config: &MarkdownConfig) -> Options {
+    let mut options = Options::empty();
+    options.set(Options::ENABLE_TABLES,             config.tables);
+    options.set(Options::ENABLE_FOOTNOTES,          config.footnotes);
+    options.set(Options::ENABLE_STRIKETHROUGH,      config.strikethrough);
+    options.set(Options::ENABLE_TASKLISTS,          config.tasklists);
+    options.set(Options::ENABLE_SMART_PUNCTUATION,  config.smart_punctuation);
+    options.set(Options::ENABLE_HEADING_ATTRIBUTES, config.heading_attributes);
+    options
+}
+
+/// A fenced code block whose contents are collected while parsing, to be highlighted in one go.
+///
+pub(crate) struct CodeBlock<'a> {
+    language: String,
+    syntax: &'a SyntaxReference,
+    pub(crate) code: String,
+}
+
+impl<'a> CodeBlock<'a> {
+    pub(crate) fn new(language: &str, syntax: &'a SyntaxReference) -> Self {
+        let language = language.split_whitespace().next().unwrap_or_default().to_string();
+        CodeBlock { language, syntax, code: String::new() }
+    }
+
+    /// Render the code block as HTML with a `<span>` for every highlighted token. Falls back to
+    /// plain escaped code if highlighting fails.
+    ///
+    /// The `<pre>` tag is given a `highlighted` class, so client-side highlighting can skip it.
+    ///
+    pub(crate) fn highlight(self, syntax_set: &SyntaxSet) -> String {
+        let mut generator =
+            ClassedHTMLGenerator::new_with_class_style(self.syntax, syntax_set, SYNTAX_CLASS_STYLE);
+
+        let result = LinesWithEndings::from(&self.code).
+            try_for_each(|line| generator.parse_html_for_line_which_includes_newline(line));
+
+        let mut output = String::from(r#"<pre class="highlighted"><code class="language-"#);
+        // [Unwrap] Writing to a String should not fail
+        escape_html(&mut output, &self.language).unwrap();
+        output.push_str(r#"">"#);
+
+        match result {
+            Ok(()) => output.push_str(&generator.finalize()),
+            Err(e) => {
+                warn!("Couldn't highlight {} code: {}", self.language, e);
+                escape_html(&mut output, &self.code).unwrap();
+            },
+        }
+
+        output.push_str("</code></pre>\n");
+        output
+    }
+}
+
+/// Render code that's not part of a markdown document, like the code blocks of other formats.
+/// It's highlighted with syntect if the language is known, otherwise that's left to the client
+/// side, like it is for markdown.
+///
+pub(crate) fn render_code(language: Option<&str>, code: String) -> String {
+    let mut output = String::new();
+
+    let language = match language {
+        Some(language) => language,
+        None => {
+            output.push_str("<pre><code>");
+            // [Unwrap] Writing to a String should not fail
+            escape_html(&mut output, &code).unwrap();
+            output.push_str("</code></pre>\n");
+            return output;
+        },
+    };
+
+    if let Some(syntax) = SYNTAX_SET.find_syntax_by_token(language) {
+        let mut code_block = CodeBlock::new(language, syntax);
+        code_block.code = code;
+        return code_block.highlight(&SYNTAX_SET);
+    }
+
+    output.push_str("<pre><code class=\"language-");
+    // [Unwrap] Writing to a String should not fail
+    escape_html(&mut output, language).unwrap();
+    output.push_str("\">");
+    escape_html(&mut output, &code).unwrap();
+    output.push_str("</code></pre>\n");
+    output
+}
+
+/// Remove everything from the HTML that's not in GitHub's allowlist: scripts, styles, iframes,
+/// event handlers, `javascript:` URLs and so on. Ids and names are given a `user-content-` prefix,
+/// like GitHub does it.
+///
+/// Local `file://` URLs are allowed, since that's how relative links and images are rendered. They
+/// can't reach anything the preview couldn't already show: the page is a local file itself, and
+/// the viewer only opens documents from them, it doesn't run anything.
+///
+pub(crate) fn sanitize_html(html: &str) -> String {
+    let tag_attributes = [
+        ("a",          &["href"][..]),
+        ("img",        &["src", "longdesc"]),
+        ("div",        &["itemscope", "itemtype"]),
+        ("blockquote", &["cite"]),
+        ("del",        &["cite"]),
+        ("ins",        &["cite"]),
+        ("q",          &["cite"]),
+    ];
+
+    let tag_attributes = tag_attributes.iter().
+        map(|(tag, attributes)| (*tag, attributes.iter().copied().collect())).
+        collect();
+
+    ammonia::Builder::empty().
+        tags(SANITIZED_TAGS.iter().copied().collect()).
+        generic_attributes(SANITIZED_ATTRIBUTES.iter().copied().collect()).
+        tag_attributes(tag_attributes).
+        clean_content_tags(["script", "style"].into_iter().collect()).
+        url_schemes(["http", "https", "mailto", "file"].into_iter().collect()).
+        url_relative(ammonia::UrlRelative::PassThrough).
+        id_prefix(Some(USER_CONTENT_PREFIX)).
+        attribute_filter(|_, attribute, value| {
+            match attribute {
+                "name" => Some(format!("{}{}", USER_CONTENT_PREFIX, value).into()),
+                _      => Some(value.into()),
+            }
+        }).
+        link_rel(None).
+        clean(html).
+        to_string()
+}
+
+/// Replace GitHub's emoji shortcodes, like `:tada:`, with the emoji they stand for. Shortcodes
+/// that aren't in the table are left as they are, and so is text in code blocks and autolinks.
+/// Inline code is a separate kind of event, so it's not affected either.
+///
+fn render_emoji(events: Vec<Event>) -> Vec<Event> {
+    use pulldown_cmark::{Tag, LinkType};
+
+    // [Unwrap] The regex is valid
+    let re_shortcode = Regex::new(r":([a-z0-9_+\-]+):").unwrap();
+    let mut skip_depth: usize = 0;
+
+    events.into_iter().map(|event| {
+        match event {
+            Event::Text(text) if skip_depth == 0 && text.contains(':') => {
+                let replaced = re_shortcode.replace_all(&text, |captures: &regex::Captures| {
+                    match emojis::get_by_shortcode(&captures[1]) {
+                        Some(emoji) => emoji.as_str().to_string(),
+                        None        => captures[0].to_string(),
+                    }
+                }).into_owned();
+
+                Event::Text(replaced.into())
+            },
+            Event::Start(Tag::CodeBlock(_) | Tag::Link(LinkType::Autolink, ..)) => {
+                skip_depth += 1;
+                event
+            },
+            Event::End(Tag::CodeBlock(_) | Tag::Link(LinkType::Autolink, ..)) => {
+                skip_depth = skip_depth.saturating_sub(1);
+                event
+            },
+            event => event,
+        }
+    }).collect()
+}
+
+/// Turns text that GitHub would link into actual links: bare URLs, issue references like `#123`
+/// or `org/repo#45`, and user mentions like `@user`. Issues and mentions are only linked if the
+/// document's repository is known.
+///
+struct Autolinker {
+    re_link: Regex,
+    re_html_link: Regex,
+    // The URL of the repository, and of the site it's hosted on
+    repository: Option<(String, String)>,
+}
+
+impl Autolinker {
+    fn new(repository_url: Option<&str>) -> Self {
+        // [Unwrap] The regex is valid
+        let re_link = Regex::new(concat!(
+            r#"(?P<url>\b(?:https?://|www\.)[^\s<]*[^\s<?!.,:;*_~'"\]])"#,
+            r"|(?P<reference>(?P<reference_repo>[\w.-]+/[\w.-]+)?#(?P<number>\d+)\b)",
+            r"|(?P<mention>@(?P<user>[a-zA-Z\d](?:[a-zA-Z\d]|-[a-zA-Z\d]){0,38})\b)",
+        )).unwrap();
+        // [Unwrap] The regex is valid
+        let re_html_link = Regex::new(r"(?i)<(?P<closing>/)?a[\s/>]").unwrap();
+
+        let repository = repository_url.and_then(|url| {
+            let url = url.trim_end_matches('/');
+            let path_start = url.find("://")? + 3;
+            let host_end = path_start + url[path_start..].find('/')?;
+
+            Some((url.to_string(), url[..host_end].to_string()))
+        });
+
+        Autolinker { re_link, re_html_link, repository }
+    }
+
+    /// Link text outside of code, links and images, including links written as raw `<a>` tags.
+    /// Text tends to be split into several events, so consecutive ones are joined together first.
+    ///
+    fn run<'a>(&self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        use pulldown_cmark::Tag;
+
+        let mut output = Vec::with_capacity(events.len());
+        let mut text = String::new();
+        let mut skip_depth: usize = 0;
+
+        for event in events {
+            match &event {
+                Event::Text(t) if skip_depth == 0 => {
+                    text.push_str(t);
+                    continue;
+                },
+                Event::Start(Tag::Link(..) | Tag::Image(..) | Tag::CodeBlock(_)) => skip_depth += 1,
+                Event::End(Tag::Link(..) | Tag::Image(..) | Tag::CodeBlock(_)) => {
+                    skip_depth = skip_depth.saturating_sub(1);
+                },
+                Event::Html(html) => {
+                    for captures in self.re_html_link.captures_iter(html) {
+                        if captures.name("closing").is_some() {
+                            skip_depth = skip_depth.saturating_sub(1);
+                        } else {
+                            skip_depth += 1;
+                        }
+                    }
+                },
+                _ => (),
+            }
+
+            self.push_linked_text(&mut output, std::mem::take(&mut text));
+            output.push(event);
+        }
+
+        self.push_linked_text(&mut output, text);
+        output
+    }
+
+    fn push_linked_text(&self, output: &mut Vec<Event<'_>>, text: String) {
+        use pulldown_cmark::{Tag, LinkType};
+
+        let mut last_end = 0;
+
+        for captures in self.re_link.captures_iter(&text) {
+            // [Unwrap] The full match is always present
+            let link_match = captures.get(0).unwrap();
+            let link_text = captures.name("url").
+                map_or(link_match.as_str(), |url| trim_url_end(url.as_str()));
+            let url = match self.link_url(&captures, link_text, &text[..link_match.start()]) {
+                Some(url) => url,
+                None => continue,
+            };
+
+            if link_match.start() > last_end {
+                output.push(Event::Text(text[last_end..link_match.start()].to_string().into()));
+            }
+
+            let tag = Tag::Link(LinkType::Autolink, url.into(), "".into());
+            output.push(Event::Start(tag.clone()));
+            output.push(Event::Text(link_text.to_string().into()));
+            output.push(Event::End(tag));
+
+            last_end = link_match.start() + link_text.len();
+        }
+
+        if last_end < text.len() {
+            output.push(Event::Text(text[last_end..].to_string().into()));
+        }
+    }
+
+    fn link_url(
+        &self,
+        captures: &regex::Captures,
+        link_text: &str,
+        preceding_text: &str,
+    ) -> Option<String> {
+        if captures.name("url").is_some() {
+            let url = link_text;
+            return if url.starts_with("www.") { Some(format!("http://{}", url)) } else { Some(url.to_string()) };
+        }
+
+        // References and mentions shouldn't be part of a word, an email or a path
+        let preceded_by_word = preceding_text.chars().next_back().
+            is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '/' | '@' | '#' | '.' | '-'));
+        if preceded_by_word {
+            return None;
+        }
+
+        let (repository_url, host_url) = self.repository.as_ref()?;
+
+        if let Some(user) = captures.name("user") {
+            Some(format!("{}/{}", host_url, user.as_str()))
+        } else if let Some(repo) = captures.name("reference_repo") {
+            Some(format!("{}/{}/issues/{}", host_url, repo.as_str(), &captures["number"]))
+        } else {
+            Some(format!("{}/issues/{}", repository_url, &captures["number"]))
+        }
     }
 }
 
+/// GitHub leaves trailing punctuation out of bare URLs, and closing parentheses as well, unless
+/// they close one that's part of the URL, like in `https://en.wikipedia.org/wiki/Foo_(bar)`.
+///
+fn trim_url_end(url: &str) -> &str {
+    let mut url = url;
+
+    loop {
+        url = url.trim_end_matches(['?', '!', '.', ',', ':', ';', '*', '_', '~', '\'', '"']);
+
+        if url.ends_with(')') && url.matches(')').count() > url.matches('(').count() {
+            url = &url[..url.len() - 1];
+        } else {
+            return url;
+        }
+    }
+}
+
+/// The unparsed front matter of a document.
+///
+enum FrontMatter<'a> {
+    Yaml(&'a str),
+    Toml(&'a str),
+}
+
+impl FrontMatter<'_> {
+    fn parse(&self) -> Option<Value> {
+        let result = match self {
+            FrontMatter::Yaml(source) => serde_yaml::from_str(source).map_err(|e| e.to_string()),
+            FrontMatter::Toml(source) => {
+                toml::from_str(source).map(toml_to_json).map_err(|e| e.to_string())
+            },
+        };
+
+        result.map_err(|e| warn!("Couldn't parse front matter: {}", e)).ok()
+    }
+}
+
+/// Separate front matter from the markdown after it. It's a block between `---` lines for YAML,
+/// or `+++` lines for TOML, that starts on the very first line of the file. YAML front matter can
+/// also be closed with a `...` line.
+///
+fn split_front_matter(contents: &str) -> (Option<FrontMatter<'_>>, &str) {
+    let bom_len = contents.len() - contents.trim_start_matches('\u{feff}').len();
+
+    let mut lines = contents[bom_len..].split_inclusive('\n');
+    let first_line = lines.next().unwrap_or_default();
+    let delimiter = match first_line.trim_end() {
+        delimiter @ ("---" | "+++") => delimiter,
+        _ => return (None, contents),
+    };
+
+    let source_start = bom_len + first_line.len();
+    let mut source_end = source_start;
+
+    for line in lines {
+        let line_end = source_end + line.len();
+        let trimmed_line = line.trim_end();
+
+        if trimmed_line == delimiter || (delimiter == "---" && trimmed_line == "...") {
+            let source = &contents[source_start..source_end];
+            let front_matter =
+                if delimiter == "---" { FrontMatter::Yaml(source) } else { FrontMatter::Toml(source) };
+
+            return (Some(front_matter), &contents[line_end..]);
+        }
+
+        source_end = line_end;
+    }
+
+    // No closing delimiter, so it's just markdown
+    (None, contents)
+}
+
+/// TOML dates and times don't have an equivalent in JSON, so they're turned into strings.
+///
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s)   => Value::from(s),
+        toml::Value::Integer(i)  => Value::from(i),
+        toml::Value::Float(f)    => Value::from(f),
+        toml::Value::Boolean(b)  => Value::from(b),
+        toml::Value::Datetime(d) => Value::from(d.to_string()),
+        toml::Value::Array(a)    => Value::Array(a.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(t)    => {
+            Value::Object(t.into_iter().map(|(k, v)| (k, toml_to_json(v))).collect())
+        },
+    }
+}
+
+/// Write down the front matter data as the contents of a `<table>`. Like on GitHub, the keys of
+/// objects are headers with their values in a single row below them. Nested objects and arrays
+/// become nested tables.
+///
+fn render_front_matter_table(output: &mut String, data: &Value) {
+    let cells: Vec<&Value> = match data {
+        Value::Object(object) => {
+            output.push_str("<thead><tr>");
+            for key in object.keys() {
+                output.push_str("<th>");
+                // [Unwrap] Writing to a String should not fail
+                escape_html(&mut *output, key).unwrap();
+                output.push_str("</th>");
+            }
+            output.push_str("</tr></thead>");
+
+            object.values().collect()
+        },
+        Value::Array(array) => array.iter().collect(),
+        other => vec![other],
+    };
+
+    output.push_str("<tbody><tr>");
+    for cell in cells {
+        output.push_str("<td>");
+        match cell {
+            Value::Object(_) | Value::Array(_) => {
+                output.push_str("<table>");
+                render_front_matter_table(output, cell);
+                output.push_str("</table>");
+            },
+            Value::String(s) => escape_html(&mut *output, s).unwrap(),
+            Value::Null => (),
+            other => output.push_str(&other.to_string()),
+        }
+        output.push_str("</td>");
+    }
+    output.push_str("</tr></tbody>");
+}
+
+/// Render the source of a diagram in a `<pre class="mermaid">` tag, which is where a diagram
+/// renderer on the client side would look for it.
+///
+fn render_diagram(source: &str) -> String {
+    let mut output = String::from(r#"<pre class="mermaid">"#);
+    // [Unwrap] Writing to a String should not fail
+    escape_html(&mut output, source).unwrap();
+    output.push_str("</pre>\n");
+    output
+}
+
+/// The types of GitHub alerts, as written in their `[!TYPE]` markers, and the titles they're
+/// rendered with.
+///
+const ALERT_TYPES: &[(&str, &str)] = &[
+    ("NOTE",      "Note"),
+    ("TIP",       "Tip"),
+    ("IMPORTANT", "Important"),
+    ("WARNING",   "Warning"),
+    ("CAUTION",   "Caution"),
+];
+
+/// Replace blockquotes that start with an alert marker on a line of its own with the markup
+/// GitHub uses for them:
+///
+/// ```markdown
+/// > [!NOTE]
+/// > Useful information.
+/// ```
+///
+/// The marker is removed and turned into a title, and the blockquote into a `div` with a class
+/// for the type of alert. A marker without any other content is left alone, like GitHub does.
+///
+fn render_alerts(events: Vec<Event>) -> Vec<Event> {
+    use pulldown_cmark::Tag;
+
+    let mut output = Vec::with_capacity(events.len());
+    // For each blockquote we're currently in, whether it's been turned into an alert
+    let mut open_blockquotes = Vec::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        match &events[index] {
+            Event::Start(Tag::BlockQuote) => {
+                match parse_alert_marker(&events[index..]) {
+                    Some((title, marker_len, ends_paragraph)) => {
+                        output.push(Event::Html(format!(
+                            "<div class=\"markdown-alert markdown-alert-{}\">\n<p class=\"markdown-alert-title\">{}</p>\n",
+                            title.to_lowercase(), title
+                        ).into()));
+
+                        if !ends_paragraph {
+                            output.push(Event::Start(Tag::Paragraph));
+                        }
+
+                        open_blockquotes.push(true);
+                        index += marker_len;
+                        continue;
+                    },
+                    None => open_blockquotes.push(false),
+                }
+            },
+            Event::End(Tag::BlockQuote) => {
+                let is_alert = open_blockquotes.pop() == Some(true);
+
+                if is_alert {
+                    output.push(Event::Html("</div>\n".into()));
+                    index += 1;
+                    continue;
+                }
+            },
+            _ => (),
+        }
+
+        output.push(events[index].clone());
+        index += 1;
+    }
+
+    output
+}
+
+/// Check if the events, starting with a blockquote, begin with an alert marker. If they do,
+/// returns the alert's title, the number of events that make up the marker and whether it's a
+/// paragraph of its own.
+///
+fn parse_alert_marker(events: &[Event]) -> Option<(&'static str, usize, bool)> {
+    use pulldown_cmark::Tag;
+
+    // The paragraph might come after the marker of its source line
+    let paragraph_index = if events.get(1).and_then(source_line).is_some() { 2 } else { 1 };
+    if !matches!(events.get(paragraph_index), Some(Event::Start(Tag::Paragraph))) {
+        return None;
+    }
+
+    // The brackets of the marker tend to come in separate text events
+    let mut marker = String::new();
+    let mut marker_end = paragraph_index + 1;
+    while let Some(Event::Text(text)) = events.get(marker_end) {
+        marker.push_str(text);
+        marker_end += 1;
+    }
+
+    let alert_type = marker.trim().strip_prefix("[!")?.strip_suffix(']')?.to_uppercase();
+    let (_, title) = ALERT_TYPES.iter().find(|(name, _)| *name == alert_type)?;
+
+    match (events.get(marker_end), events.get(marker_end + 1)) {
+        (Some(Event::SoftBreak | Event::HardBreak), _) => Some((title, marker_end + 1, false)),
+        (Some(Event::End(Tag::Paragraph)), Some(next)) if *next != Event::End(Tag::BlockQuote) => {
+            Some((title, marker_end + 1, true))
+        },
+        _ => None,
+    }
+}
+
+/// Finds the line in the markdown file that a position in the parsed markdown comes from. The
+/// front matter is cut off and math is replaced with placeholders before parsing, so the lines
+/// they take up need to be added back.
+///
+struct SourceLines {
+    first_line: usize,
+    line_starts: Vec<usize>,
+    math_line_breaks: Vec<(usize, usize)>,
+}
+
+impl SourceLines {
+    fn new(first_line: usize, markdown: &str, math: &Math) -> Self {
+        let line_starts = std::iter::once(0).
+            chain(markdown.match_indices('\n').map(|(index, _)| index + 1)).
+            collect();
+
+        SourceLines { first_line, line_starts, math_line_breaks: math.line_breaks.clone() }
+    }
+
+    /// The 1-based line number of the given byte offset in the parsed markdown.
+    ///
+    fn line(&self, offset: usize) -> usize {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+
+        let math_count = self.math_line_breaks.partition_point(|(end, _)| *end <= offset);
+        let math_lines = math_count.checked_sub(1).map_or(0, |i| self.math_line_breaks[i].1);
+
+        self.first_line + line + math_lines
+    }
+}
+
+/// The block elements that get a `data-source-line` attribute.
+///
+fn is_annotated_block(event: &Event) -> bool {
+    use pulldown_cmark::Tag;
+
+    matches!(event,
+        Event::Start(Tag::Paragraph | Tag::Heading(..) | Tag::BlockQuote | Tag::CodeBlock(_) |
+            Tag::List(_) | Tag::Item) |
+        Event::Rule
+    )
+}
+
+/// Source lines are tracked with markers placed right before the block they belong to. Other
+/// stages of rendering work with pulldown-cmark's events, so the opening tags are only written
+/// out with their attributes at the very end, in [`render_source_lines`].
+///
+/// The markers are HTML events wrapped in private-use characters, like the placeholders of
+/// [`Math`]. HTML that comes from the document always starts with a `<`, so it can't be mistaken
+/// for a marker.
+///
+fn source_line_marker(line: usize) -> Event<'static> {
+    Event::Html(format!("\u{E002}{}\u{E003}", line).into())
+}
+
+fn source_line(event: &Event) -> Option<usize> {
+    match event {
+        Event::Html(html) => html.strip_prefix('\u{E002}')?.strip_suffix('\u{E003}')?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Replace source line markers and the opening tags of the blocks after them with tags that have
+/// a `data-source-line` attribute. Blocks that the renderer writes as HTML itself, like headings
+/// and highlighted code, get the attribute added to their first tag.
+///
+fn render_source_lines(events: Vec<Event>) -> Vec<Event> {
+    use pulldown_cmark::{Tag, CodeBlockKind};
+
+    let mut output = Vec::with_capacity(events.len());
+    let mut line = None;
+
+    for event in events {
+        if let Some(marker_line) = source_line(&event) {
+            line = Some(marker_line);
+            continue;
+        }
+
+        let attribute = match line.take() {
+            Some(line) => format!(" data-source-line=\"{}\"", line),
+            None => {
+                output.push(event);
+                continue;
+            },
+        };
+
+        let html = match &event {
+            Event::Start(Tag::Paragraph)         => format!("<p{}>", attribute),
+            Event::Start(Tag::BlockQuote)        => format!("<blockquote{}>\n", attribute),
+            Event::Start(Tag::List(None))        => format!("<ul{}>\n", attribute),
+            Event::Start(Tag::List(Some(1)))     => format!("<ol{}>\n", attribute),
+            Event::Start(Tag::List(Some(start))) => format!("<ol start=\"{}\"{}>\n", start, attribute),
+            Event::Start(Tag::Item)              => format!("<li{}>", attribute),
+            Event::Rule                          => format!("<hr{} />\n", attribute),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split(' ').next().unwrap_or_default(),
+                    CodeBlockKind::Indented => "",
+                };
+
+                let mut html = format!("<pre{}><code", attribute);
+                if !language.is_empty() {
+                    html.push_str(" class=\"language-");
+                    // [Unwrap] Writing to a String should not fail
+                    escape_html(&mut html, language).unwrap();
+                    html.push('"');
+                }
+                html.push('>');
+                html
+            },
+            Event::Html(html) if html.starts_with('<') => {
+                let tag_end = html.find(|c: char| c.is_whitespace() || c == '>' || c == '/').
+                    unwrap_or(html.len());
+                format!("{}{}{}", &html[..tag_end], attribute, &html[tag_end..])
+            },
+            _ => {
+                output.push(event);
+                continue;
+            },
+        };
+
+        output.push(Event::Html(html.into()));
+    }
+
+    output
+}
+
+/// Math written like GitHub expects it, `$inline$` or `$$display$$`, cut out of the markdown
+/// source before parsing and replaced by placeholders, so markdown syntax inside of it, like `*`
+/// or `_`, is left alone. After parsing, the placeholders are turned into a `<span>` with a
+/// `math-inline` or `math-display` class. The dollar signs are kept, so it's readable as it is
+/// until it's typeset by KaTeX in the page.
+///
+struct Math {
+    expressions: Vec<String>,
+    re_placeholder: Regex,
+    // For each placeholder, the offset right after it in the extracted markdown, and the total
+    // number of line breaks in the math replaced up to that point
+    line_breaks: Vec<(usize, usize)>,
+}
+
+impl Math {
+    fn new() -> Self {
+        // [Unwrap] The regex is valid
+        let re_placeholder = Regex::new("\u{E000}(\\d+)\u{E001}").unwrap();
+        Math { expressions: Vec::new(), re_placeholder, line_breaks: Vec::new() }
+    }
+
+    /// Find math in the markdown outside of code, raw HTML, images and the URLs and titles of
+    /// links. Returns the markdown with the math replaced by placeholders.
+    ///
+    fn extract(markdown: &str, options: Options) -> (String, Self) {
+        use pulldown_cmark::{Tag, LinkType};
+
+        let mut math = Math::new();
+        let mut line_break_count = 0;
+
+        let parser = Parser::new_ext(markdown, options).into_offset_iter();
+        let mut skipped_ranges: Vec<_> = parser.reference_definitions().iter().
+            map(|(_, link_def)| link_def.span.clone()).
+            collect();
+        // The end of the text of each link we're in, after which come its URL and title
+        let mut link_text_ends = Vec::new();
+
+        for (event, range) in parser {
+            let is_link_tag = matches!(event, Event::Start(Tag::Link(..)) | Event::End(Tag::Link(..)));
+            if let Some(text_end) = link_text_ends.last_mut().filter(|_| !is_link_tag) {
+                *text_end = range.end;
+            }
+
+            match event {
+                Event::Start(Tag::CodeBlock(_)) |
+                    Event::Start(Tag::Image(..)) |
+                    Event::Code(_) |
+                    Event::Html(_) => skipped_ranges.push(range),
+                Event::Start(Tag::Link(LinkType::Autolink | LinkType::Email, ..)) => {
+                    skipped_ranges.push(range.clone());
+                    link_text_ends.push(range.end);
+                },
+                Event::Start(Tag::Link(..)) => link_text_ends.push(range.start),
+                Event::End(Tag::Link(..)) => {
+                    let text_end = link_text_ends.pop().unwrap_or(range.start);
+                    skipped_ranges.push(text_end..range.end);
+                },
+                _ => (),
+            }
+        }
+        skipped_ranges.sort_by_key(|range| range.start);
+        skipped_ranges.push(markdown.len()..markdown.len());
+
+        let mut output = String::with_capacity(markdown.len());
+        let mut segment_start = 0;
+
+        for range in skipped_ranges {
+            if range.start < segment_start {
+                continue;
+            }
+
+            let mut segment = &markdown[segment_start..range.start];
+            while let Some(span) = find_math(segment) {
+                output.push_str(&segment[..span.start]);
+                output.push_str(&format!("\u{E000}{}\u{E001}", math.expressions.len()));
+                math.expressions.push(segment[span.start..span.end].to_string());
+
+                line_break_count += segment[span.start..span.end].matches('\n').count();
+                math.line_breaks.push((output.len(), line_break_count));
+
+                segment = &segment[span.end..];
+            }
+            output.push_str(segment);
+            output.push_str(&markdown[range.clone()]);
+
+            segment_start = range.end;
+        }
+
+        (output, math)
+    }
+
+    /// Put the original math back into plain text that has placeholders in it.
+    ///
+    fn restore(&self, text: &str) -> String {
+        self.re_placeholder.replace_all(text, |captures: &regex::Captures| {
+            self.expression(&captures[1]).unwrap_or_default().to_string()
+        }).into_owned()
+    }
+
+    /// Replace the placeholders in text events with the HTML for the math they stand for.
+    ///
+    fn render<'a>(&self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        let mut output = Vec::with_capacity(events.len());
+
+        for event in events {
+            let text = match &event {
+                Event::Text(text) if self.re_placeholder.is_match(text) => text,
+                _ => {
+                    output.push(event);
+                    continue;
+                },
+            };
+
+            let mut last_end = 0;
+            for captures in self.re_placeholder.captures_iter(text) {
+                // [Unwrap] The full match is always present
+                let placeholder = captures.get(0).unwrap();
+                if placeholder.start() > last_end {
+                    output.push(Event::Text(text[last_end..placeholder.start()].to_string().into()));
+                }
+                if let Some(expression) = self.expression(&captures[1]) {
+                    output.push(Event::Html(render_math_expression(expression).into()));
+                }
+                last_end = placeholder.end();
+            }
+            if last_end < text.len() {
+                output.push(Event::Text(text[last_end..].to_string().into()));
+            }
+        }
+
+        output
+    }
+
+    fn expression(&self, index: &str) -> Option<&str> {
+        let index: usize = index.parse().ok()?;
+        self.expressions.get(index).map(String::as_str)
+    }
+}
+
+fn render_math_expression(expression: &str) -> String {
+    let class = if expression.starts_with("$$") { "math-display" } else { "math-inline" };
+    let mut html = format!(r#"<span class="math {}">"#, class);
+    // [Unwrap] Writing to a String should not fail
+    escape_html(&mut html, expression).unwrap();
+    html.push_str("</span>");
+    html
+}
+
+/// The position of a math expression in a piece of text, including the dollar signs.
+///
+struct MathSpan {
+    start: usize,
+    end: usize,
+}
+
+/// Find the first math expression in the text. Inline math can't start or end with whitespace
+/// and can't be followed by a digit, so prices like "$5 or $10" are left alone. Neither kind can
+/// span paragraphs or start with an escaped dollar sign.
+///
+fn find_math(text: &str) -> Option<MathSpan> {
+    let mut search_start = 0;
+
+    while let Some(offset) = text[search_start..].find('$') {
+        let start = search_start + offset;
+
+        if text[..start].ends_with('\\') {
+            search_start = start + 1;
+            continue;
+        }
+
+        if text[start..].starts_with("$$") {
+            let content_start = start + 2;
+
+            if let Some(len) = text[content_start..].find("$$") {
+                let content = &text[content_start..(content_start + len)];
+
+                if !content.trim().is_empty() && !content.contains("\n\n") {
+                    return Some(MathSpan { start, end: content_start + len + 2 });
+                }
+            }
+
+            search_start = content_start;
+            continue;
+        }
+
+        let content_start = start + 1;
+
+        if let Some(len) = text[content_start..].find('$') {
+            let content = &text[content_start..(content_start + len)];
+            let end = content_start + len + 1;
+
+            if !content.is_empty() &&
+                !content.contains("\n\n") &&
+                !content.starts_with(char::is_whitespace) &&
+                !content.ends_with(char::is_whitespace) &&
+                !text[end..].starts_with(|c: char| c.is_ascii_digit()) {
+                return Some(MathSpan { start, end });
+            }
+        }
+
+        search_start = content_start;
+    }
+
+    None
+}
+
 /// The output of the rendering process. Includes both the rendered HTML and additional metadata
 /// used by its clients.
 ///
@@ -85,4 +1229,92 @@ pub struct RenderedContent {
 
     /// All the languages in fenced code blocks from the markdown input.
     pub code_languages: HashSet<String>,
+
+    /// All the headings in the document, in order.
+    pub headings: Vec<Heading>,
+
+    /// The data in the document's YAML or TOML front matter, if it has any that could be parsed.
+    pub front_matter: Option<Value>,
+
+    /// The local image files shown in the document, so changes to them can be watched for.
+    pub local_images: HashSet<PathBuf>,
+
+    /// Whether the document has any diagrams, which need a script to be drawn.
+    pub has_diagrams: bool,
+
+    /// Whether the document has any math, which needs a script to be typeset.
+    pub has_math: bool,
+}
+
+/// A heading in the rendered document, used to build an outline of it.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    /// From 1 to 6, for `<h1>` to `<h6>`.
+    pub level: u32,
+
+    /// The id to use as a link target. With sanitized HTML, the heading's element has it with a
+    /// `user-content-` prefix, and links to it are resolved by the page's script.
+    pub id: String,
+
+    /// The contents of the heading as plain text, without any inline markup.
+    pub text: String,
+}
+
+impl Heading {
+    pub(crate) fn opening_tag(&self, classes: &[&str]) -> String {
+        let mut output = format!("<h{} id=\"", self.level);
+        // [Unwrap] Writing to a String should not fail
+        escape_html(&mut output, &self.id).unwrap();
+        output.push('"');
+
+        if !classes.is_empty() {
+            output.push_str(" class=\"");
+            escape_html(&mut output, &classes.join(" ")).unwrap();
+            output.push('"');
+        }
+
+        output.push('>');
+        output
+    }
+}
+
+/// Generates heading ids the way GitHub does it: lowercase text with punctuation removed and
+/// spaces turned into dashes. Repeated ids get a numeric suffix to keep them unique, like
+/// `heading`, `heading-1`, `heading-2`. Explicitly given ids are kept as they are.
+///
+pub(crate) struct Slugger {
+    re_removed_chars: Regex,
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    pub(crate) fn new() -> Self {
+        // Anything that's not a letter, a number, an accent, an underscore, a dash or a space
+        // [Unwrap] The regex is valid
+        let re_removed_chars = Regex::new(r"[^\p{L}\p{M}\p{N}\p{Pc} \-]").unwrap();
+        Slugger { re_removed_chars, occurrences: HashMap::new() }
+    }
+
+    pub(crate) fn slug(&mut self, text: &str) -> String {
+        let base = self.re_removed_chars.replace_all(&text.to_lowercase(), "").replace(' ', "-");
+
+        let mut slug = base.clone();
+        while self.occurrences.contains_key(&slug) {
+            // [Unwrap] The loop only runs if there's already a slug, so the base is there as well
+            let count = self.occurrences.get_mut(&base).unwrap();
+            *count += 1;
+            slug = format!("{}-{}", base, count);
+        }
+
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+
+    /// Use the given id verbatim, only making sure generated slugs don't end up the same.
+    ///
+    pub(crate) fn reserve(&mut self, id: &str) -> String {
+        self.occurrences.entry(id.to_string()).or_insert(0);
+        id.to_string()
+    }
 }
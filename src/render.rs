@@ -0,0 +1,68 @@
+//! Rendering of documents into HTML, whatever their format.
+//!
+//! Each [`DocumentFormat`] has its own renderer: [`markdown::Renderer`], [`rst::Renderer`] and
+//! [`asciidoc::Renderer`]. They all produce the same [`RenderedContent`], so the rest of the app
+//! doesn't need to care which one it's dealing with. The one to use is picked by the file's
+//! extension, with markdown as the fallback, since that's what the app is mostly about.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::asciidoc;
+use crate::input::{Config, DocumentFormat};
+use crate::markdown::{self, RenderedContent};
+use crate::rst;
+
+/// Turns a document on the filesystem into HTML.
+///
+pub trait Render {
+    /// The canonicalized path of the document, to use in file operations.
+    fn canonical_path(&self) -> &Path;
+
+    /// Turn the current contents of the document into HTML.
+    fn run(&self) -> Result<RenderedContent, io::Error>;
+}
+
+impl<R: Render + ?Sized> Render for Box<R> {
+    fn canonical_path(&self) -> &Path {
+        (**self).canonical_path()
+    }
+
+    fn run(&self) -> Result<RenderedContent, io::Error> {
+        (**self).run()
+    }
+}
+
+/// Create the renderer for the given document, depending on its [`DocumentFormat`]. Files with
+/// unknown extensions (like the temporary file that STDIN is written to) are treated as markdown.
+/// Documents are rendered with source lines, so the preview can be scrolled to a line of the file.
+///
+pub fn renderer_for(path: PathBuf, config: &Config) -> Box<dyn Render + Send> {
+    match DocumentFormat::from_path(&path) {
+        Some(DocumentFormat::Rst) => Box::new(rst::Renderer::new(path, config).with_source_lines()),
+        Some(DocumentFormat::AsciiDoc) => {
+            Box::new(asciidoc::Renderer::new(path, config).with_source_lines())
+        },
+        Some(DocumentFormat::Markdown) | None => {
+            Box::new(markdown::Renderer::new(path, config).with_source_lines())
+        },
+    }
+}
+
+/// Create the renderer for a standalone export of the given document, picked the same way as in
+/// [`renderer_for`]. Relative links are kept as they're written, so they keep working next to the
+/// original files instead of pointing into the directory the document was exported from.
+///
+pub fn export_renderer_for(path: PathBuf, config: &Config) -> Box<dyn Render + Send> {
+    match DocumentFormat::from_path(&path) {
+        Some(DocumentFormat::Rst) => {
+            Box::new(rst::Renderer::new(path, config).with_relative_links())
+        },
+        Some(DocumentFormat::AsciiDoc) => {
+            Box::new(asciidoc::Renderer::new(path, config).with_relative_links())
+        },
+        Some(DocumentFormat::Markdown) | None => {
+            Box::new(markdown::Renderer::new(path, config).with_relative_links())
+        },
+    }
+}
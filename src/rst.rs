@@ -0,0 +1,761 @@
+//! reStructuredText rendering.
+//!
+//! A native renderer for the parts of reStructuredText that documentation tends to use: section
+//! headings, paragraphs, bullet and enumerated lists, literal and code blocks, block quotes,
+//! admonitions, links, images and inline markup. Other directives are skipped, the same way
+//! comments are.
+//!
+//! The output is the same [`RenderedContent`] that the markdown renderer produces. Headings get
+//! the same kind of ids and code is highlighted the same way, and admonitions like `.. note::` are
+//! shown like GitHub-style alerts in markdown. Top-level blocks can be given the lines they come
+//! from, so the preview can be scrolled to a line of the file. With the `sanitize_html` config
+//! setting, the HTML is cleaned up the same way as well.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{PathBuf, Path};
+
+use pulldown_cmark::escape::{escape_href, escape_html};
+use regex::{Captures, Regex};
+
+use crate::input::Config;
+use crate::markdown::{self, Heading, RenderedContent, Slugger};
+use crate::render::Render;
+
+/// The characters that section titles can be underlined (and overlined) with.
+///
+const ADORNMENT_CHARS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// Encapsulates a reStructuredText file and provides an interface to turn its contents into HTML.
+///
+pub struct Renderer {
+    /// The original path given to the renderer.
+    pub rst_path: PathBuf,
+
+    /// The canonicalized path to use in file operations.
+    pub canonical_rst_path: PathBuf,
+
+    sanitize_html: bool,
+    source_lines: bool,
+    resolve_links: bool,
+}
+
+impl Renderer {
+    /// Create a new renderer instance that wraps the given reStructuredText file.
+    ///
+    pub fn new(rst_path: PathBuf, config: &Config) -> Self {
+        let canonical_rst_path = rst_path.canonicalize().
+            unwrap_or_else(|_| rst_path.clone());
+        let sanitize_html = config.sanitize_html;
+
+        Renderer {
+            rst_path, canonical_rst_path, sanitize_html,
+            source_lines: false,
+            resolve_links: true,
+        }
+    }
+
+    /// Give the top-level blocks of the document a `data-source-line` attribute with the
+    /// (1-based) line of the file they start on, like the markdown renderer does.
+    ///
+    pub fn with_source_lines(mut self) -> Self {
+        self.source_lines = true;
+        self
+    }
+
+    /// Keep relative links as they're written, for a standalone export, like
+    /// [`markdown::Renderer::with_relative_links`] does.
+    ///
+    pub fn with_relative_links(mut self) -> Self {
+        self.resolve_links = false;
+        self
+    }
+
+    /// Turn the current contents of the file into HTML.
+    ///
+    pub fn run(&self) -> Result<RenderedContent, io::Error> {
+        let contents = fs::read_to_string(&self.canonical_rst_path)?;
+        let root_dir = self.canonical_rst_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let lines: Vec<&str> = contents.lines().map(str::trim_end).collect();
+        let mut parser = BlockParser {
+            inline:         InlineParser::new(&contents, root_dir, self.resolve_links),
+            heading_styles: Vec::new(),
+            slugger:        Slugger::new(),
+            headings:       Vec::new(),
+            languages:      HashSet::new(),
+            source_lines:   self.source_lines,
+        };
+
+        let mut output = String::new();
+        parser.blocks(&lines, false, &mut output);
+
+        if self.sanitize_html {
+            output = markdown::sanitize_html(&output);
+        }
+
+        Ok(RenderedContent {
+            html: output,
+            code_languages: parser.languages,
+            headings: parser.headings,
+            front_matter: None,
+            local_images: parser.inline.local_images,
+            has_diagrams: false,
+            has_math: false,
+        })
+    }
+}
+
+impl Render for Renderer {
+    fn canonical_path(&self) -> &Path {
+        &self.canonical_rst_path
+    }
+
+    fn run(&self) -> Result<RenderedContent, io::Error> {
+        Renderer::run(self)
+    }
+}
+
+/// Splits lines into blocks and renders them, keeping track of the state that's shared across the
+/// whole document.
+///
+struct BlockParser {
+    inline: InlineParser,
+    // Sections get levels in the order their underline styles first show up in the document
+    heading_styles: Vec<(char, bool)>,
+    slugger: Slugger,
+    headings: Vec<Heading>,
+    languages: HashSet<String>,
+    // Only the top-level blocks are annotated, since nested ones are given dedented lines
+    source_lines: bool,
+}
+
+impl BlockParser {
+    /// Render the given lines, which are expected to be dedented to the level of the block that
+    /// contains them. In a `tight` block, like a list item without blank lines in it, paragraphs
+    /// aren't wrapped in `<p>` tags.
+    ///
+    fn blocks(&mut self, lines: &[&str], tight: bool, output: &mut String) {
+        let source_lines = std::mem::replace(&mut self.source_lines, false);
+        // The positions in the output where blocks start, with the lines they come from
+        let mut block_starts = Vec::new();
+        let mut index = 0;
+
+        while index < lines.len() {
+            let line = lines[index];
+
+            if line.is_empty() {
+                index += 1;
+                continue;
+            }
+
+            if source_lines {
+                block_starts.push((output.len(), index + 1));
+            }
+
+            // Section title with an overline and an underline
+            match (adornment(line), lines.get(index + 1), lines.get(index + 2)) {
+                (Some(style), Some(title), Some(underline))
+                    if !title.is_empty() && adornment(underline) == Some(style) => {
+                    self.heading(title.trim(), (style, true), output);
+                    index += 3;
+                    continue;
+                },
+                _ => (),
+            }
+
+            // Section title with just an underline
+            let underline_style = lines.get(index + 1).
+                filter(|underline| underline.len() >= line.chars().count().min(3)).
+                and_then(|underline| adornment(underline));
+
+            if let Some(style) = underline_style.filter(|_| indentation(line) == 0) {
+                self.heading(line, (style, false), output);
+                index += 2;
+                continue;
+            }
+
+            // A line of punctuation on its own is a transition
+            if adornment(line).is_some() && line.len() >= 4 {
+                output.push_str("<hr />\n");
+                index += 1;
+                continue;
+            }
+
+            // Explicit markup starts with ".. ", a line with just ".." is an empty comment
+            if let Some(markup) = line.strip_prefix(".. ").or((line == "..").then_some("")) {
+                let (body, end) = indented_block(lines, index + 1);
+                self.explicit_markup(markup.trim(), &body, output);
+                index = end;
+                continue;
+            }
+
+            if list_marker(line).is_some() {
+                index = self.list(lines, index, output);
+                continue;
+            }
+
+            if indentation(line) > 0 {
+                let (body, end) = indented_block(lines, index);
+                output.push_str("<blockquote>\n");
+                self.blocks(&body, false, output);
+                output.push_str("</blockquote>\n");
+                index = end;
+                continue;
+            }
+
+            index = self.paragraph(lines, index, tight, output);
+        }
+
+        // Going backwards, so inserting attributes doesn't move the positions that are left.
+        // Blocks that render nothing, like comments, share a position with the next one.
+        let mut last_position = None;
+        for (position, line) in block_starts.into_iter().rev() {
+            if last_position != Some(position) {
+                add_source_line(output, position, line);
+                last_position = Some(position);
+            }
+        }
+
+        self.source_lines = source_lines;
+    }
+
+    fn heading(&mut self, title: &str, style: (char, bool), output: &mut String) {
+        let position = self.heading_styles.iter().position(|s| *s == style).
+            unwrap_or_else(|| {
+                self.heading_styles.push(style);
+                self.heading_styles.len() - 1
+            });
+
+        let level = (position as u32 + 1).min(6);
+        let text = self.inline.to_text(title);
+        let id = self.slugger.slug(&text);
+        let heading = Heading { level, id, text };
+
+        output.push_str(&heading.opening_tag(&[]));
+        output.push_str(&self.inline.to_html(title));
+        output.push_str(&format!("</h{}>\n", level));
+
+        self.headings.push(heading);
+    }
+
+    /// Render the paragraph starting at `start` and return the index of the line after it. A
+    /// paragraph that ends in `::` is followed by a literal block, which is rendered as well.
+    ///
+    fn paragraph(&mut self, lines: &[&str], start: usize, tight: bool, output: &mut String) -> usize {
+        let mut end = start;
+        while end < lines.len() && !lines[end].is_empty() {
+            end += 1;
+        }
+
+        let text = lines[start..end].join("\n");
+        let (text, literal_block) =
+            if text == "::" {
+                (String::new(), true)
+            } else if let Some(text) = text.strip_suffix(" ::") {
+                (text.to_string(), true)
+            } else if let Some(text) = text.strip_suffix("::") {
+                (format!("{}:", text), true)
+            } else {
+                (text, false)
+            };
+
+        if !text.is_empty() {
+            let html = self.inline.to_html(&text);
+
+            if tight {
+                output.push_str(&html);
+                output.push('\n');
+            } else {
+                output.push_str(&format!("<p>{}</p>\n", html));
+            }
+        }
+
+        if !literal_block {
+            return end;
+        }
+
+        let (body, block_end) = indented_block(lines, end);
+        if body.is_empty() {
+            return end;
+        }
+
+        self.code_block(None, &body, output);
+        block_end
+    }
+
+    /// Render the list that starts at `start` and return the index of the line after it. Items
+    /// continue for as long as lines are indented past their marker.
+    ///
+    fn list(&mut self, lines: &[&str], start: usize, output: &mut String) -> usize {
+        // [Unwrap] Only called on a line with a list marker
+        let (kind, _) = list_marker(lines[start]).unwrap();
+        let mut index = start;
+
+        match kind {
+            ListKind::Bullet(_) => output.push_str("<ul>\n"),
+            ListKind::Enumerated(Some(number)) if number != 1 => {
+                output.push_str(&format!("<ol start=\"{}\">\n", number));
+            },
+            ListKind::Enumerated(_) => output.push_str("<ol>\n"),
+        }
+
+        while let Some((item_kind, content_start)) = lines.get(index).and_then(|l| list_marker(l)) {
+            if !kind.continues_with(&item_kind) {
+                break;
+            }
+
+            let (rest, end) = indented_block(lines, index + 1);
+            let tight = !rest.iter().any(|l| l.is_empty());
+
+            let mut item = vec![&lines[index][content_start..]];
+            item.extend(rest);
+
+            let mut item_html = String::new();
+            self.blocks(&item, tight, &mut item_html);
+            output.push_str(&format!("<li>{}</li>\n", item_html.trim_end()));
+
+            // Items can be separated by blank lines
+            index = end;
+            let next_item = lines[index..].iter().position(|l| !l.is_empty()).map(|p| index + p);
+            match next_item {
+                Some(next) if list_marker(lines[next]).is_some() => index = next,
+                _ => break,
+            }
+        }
+
+        match kind {
+            ListKind::Bullet(_)     => output.push_str("</ul>\n"),
+            ListKind::Enumerated(_) => output.push_str("</ol>\n"),
+        }
+
+        index
+    }
+
+    /// Handle a line starting with `..`: a directive, a hyperlink target or a comment. The `body`
+    /// is the dedented block that follows it. Targets have already been collected by the
+    /// [`InlineParser`], and comments and unknown directives are not rendered at all.
+    ///
+    fn explicit_markup(&mut self, markup: &str, body: &[&str], output: &mut String) {
+        let (name, argument) = match markup.split_once("::") {
+            Some((name, argument)) if is_directive_name(name) => (name, argument.trim()),
+            _ => return,
+        };
+
+        let option_count = body.iter().take_while(|l| l.starts_with(':')).count();
+        let options: HashMap<&str, &str> = body[..option_count].iter().
+            filter_map(|l| l[1..].split_once(':')).
+            map(|(key, value)| (key, value.trim())).
+            collect();
+        let content = &body[option_count..];
+        let content = &content[content.iter().take_while(|l| l.is_empty()).count()..];
+
+        match name {
+            "code" | "code-block" | "sourcecode" => {
+                let language = argument.split_whitespace().next();
+                self.code_block(language, content, output);
+            },
+            "image" => {
+                output.push_str(&format!("<p>{}</p>\n", self.inline.image(argument, &options)));
+            },
+            "figure" => {
+                output.push_str("<figure>\n");
+                output.push_str(&self.inline.image(argument, &options));
+                output.push('\n');
+
+                if content.iter().any(|l| !l.is_empty()) {
+                    output.push_str("<figcaption>\n");
+                    self.blocks(content, false, output);
+                    output.push_str("</figcaption>\n");
+                }
+                output.push_str("</figure>\n");
+            },
+            "note" | "tip" | "hint" | "important" | "warning" | "attention" | "caution" |
+                "danger" | "error" => {
+                let alert_type = match name {
+                    "hint"             => "tip",
+                    "attention"        => "warning",
+                    "danger" | "error" => "caution",
+                    _                  => name,
+                };
+                // Alert types are never empty, and they're ASCII
+                let mut title = alert_type.to_string();
+                title[..1].make_ascii_uppercase();
+
+                output.push_str(&format!(
+                    "<div class=\"markdown-alert markdown-alert-{}\">\n<p class=\"markdown-alert-title\">{}</p>\n",
+                    alert_type, title
+                ));
+
+                // The first paragraph can start on the same line as the directive
+                let mut lines = Vec::new();
+                if !argument.is_empty() {
+                    lines.push(argument);
+                }
+                lines.extend(content);
+
+                self.blocks(&lines, false, output);
+                output.push_str("</div>\n");
+            },
+            _ => (),
+        }
+    }
+
+    /// Render code, highlighting it with syntect if the language is known, or leaving that to the
+    /// client side, like the markdown renderer does.
+    ///
+    fn code_block(&mut self, language: Option<&str>, lines: &[&str], output: &mut String) {
+        let lines = &lines[lines.iter().take_while(|l| l.is_empty()).count()..];
+        let mut code = lines.join("\n");
+        code.push('\n');
+
+        if let Some(language) = language {
+            self.languages.insert(language.to_string());
+        }
+        output.push_str(&markdown::render_code(language, code));
+    }
+}
+
+/// Renders the markup inside paragraphs, headings and list items: emphasis, literals, links and
+/// references to hyperlink targets defined anywhere in the document.
+///
+struct InlineParser {
+    re_markup: Regex,
+    re_url_scheme: Regex,
+    root_dir: PathBuf,
+    resolve_links: bool,
+    // Hyperlink targets, keyed by their normalized names
+    targets: HashMap<String, String>,
+    local_images: HashSet<PathBuf>,
+}
+
+impl InlineParser {
+    fn new(contents: &str, root_dir: &Path, resolve_links: bool) -> Self {
+        // [Unwrap] The regexes are valid
+        let re_markup = Regex::new(concat!(
+            r"\\(?P<escaped>(?s:.))",
+            r"|``(?P<literal>.+?)``",
+            r"|`(?P<link_text>[^`<]*?)\s*<(?P<link_url>[^<>`]+)>`__?",
+            r"|`(?P<reference>[^`]+)`__?",
+            r"|(?::(?P<role>[\w-]+):)?`(?P<interpreted>[^`]+)`",
+            r"|\*\*(?P<strong>(?:\\.|[^*\\])+)\*\*",
+            r"|\*(?P<emphasis>(?:\\.|[^*\s\\])(?:(?:\\.|[^*\\])*(?:\\.|[^*\s\\]))?)\*",
+            r#"|(?P<url>\bhttps?://[^\s<>`]*[^\s<>`.,;:!?)'"])"#,
+            r"|\b(?P<word_reference>\w(?:[\w.-]*\w)?)_\b",
+        )).unwrap();
+        let re_url_scheme = Regex::new(r"^[a-zA-Z][a-zA-Z\d+.-]*:").unwrap();
+
+        let re_target = Regex::new(r"(?m)^\s*\.\. _(?P<name>`[^`]+`|[^:]+):\s*(?P<url>\S+)\s*$").unwrap();
+        let re_embedded_target = Regex::new(r"`(?P<name>[^`<]*?)\s*<(?P<url>[^<>`]+)>`_\b").unwrap();
+
+        let mut targets = HashMap::new();
+        for captures in re_target.captures_iter(contents).chain(re_embedded_target.captures_iter(contents)) {
+            targets.insert(normalize_reference(&captures["name"]), captures["url"].to_string());
+        }
+
+        InlineParser {
+            re_markup, re_url_scheme, targets,
+            root_dir: root_dir.to_path_buf(),
+            resolve_links,
+            local_images: HashSet::new(),
+        }
+    }
+
+    fn to_html(&self, text: &str) -> String {
+        self.render(text, true)
+    }
+
+    /// The text without any markup, for the outline and heading ids.
+    ///
+    fn to_text(&self, text: &str) -> String {
+        self.render(text, false)
+    }
+
+    fn render(&self, text: &str, html: bool) -> String {
+        let mut output = String::new();
+        let mut last_end = 0;
+
+        for captures in self.re_markup.captures_iter(text) {
+            // [Unwrap] The full match is always present
+            let markup = captures.get(0).unwrap();
+
+            self.push_text(&mut output, &text[last_end..markup.start()], html);
+            self.push_markup(&mut output, &captures, html);
+            last_end = markup.end();
+        }
+
+        self.push_text(&mut output, &text[last_end..], html);
+        output
+    }
+
+    fn push_markup(&self, output: &mut String, captures: &Captures, html: bool) {
+        let (tag, content) =
+            if let Some(escaped) = captures.name("escaped") {
+                // Never markup, and the backslash is not rendered. Escaped whitespace is removed
+                // entirely, so it can separate markup from the text around it.
+                return self.push_text(output, escaped.as_str().trim(), html);
+            } else if let Some(literal) = captures.name("literal") {
+                ("code", literal.as_str())
+            } else if let Some(strong) = captures.name("strong") {
+                ("strong", strong.as_str())
+            } else if let Some(emphasis) = captures.name("emphasis") {
+                ("em", emphasis.as_str())
+            } else if let Some(interpreted) = captures.name("interpreted") {
+                let tag = match captures.name("role").map(|r| r.as_str()) {
+                    Some("code" | "literal") => "code",
+                    Some("strong")           => "strong",
+                    Some("emphasis")         => "em",
+                    _                        => "cite",
+                };
+                (tag, interpreted.as_str())
+            } else {
+                return self.push_link(output, captures, html);
+            };
+
+        // Literals are shown exactly as they're written, backslashes and all
+        let content = if tag == "code" { content.to_string() } else { unescape(content) };
+
+        if html {
+            output.push_str(&format!("<{}>", tag));
+            self.push_text(output, &content, html);
+            output.push_str(&format!("</{}>", tag));
+        } else {
+            output.push_str(&content);
+        }
+    }
+
+    fn push_link(&self, output: &mut String, captures: &Captures, html: bool) {
+        let (text, url) =
+            if let Some(url) = captures.name("link_url") {
+                let url = url.as_str();
+                let text = captures.name("link_text").map(|t| t.as_str()).filter(|t| !t.is_empty());
+                // A URL ending in an underscore is a reference to another target
+                let url = match url.strip_suffix('_').and_then(|name| self.target(name)) {
+                    Some(target) => target,
+                    None         => url.to_string(),
+                };
+
+                (text.unwrap_or(&url).to_string(), url)
+            } else if let Some(reference) = captures.name("reference") {
+                // References that aren't to a target are probably to a section title
+                let reference = reference.as_str();
+                let url = self.target(reference).
+                    unwrap_or_else(|| format!("#{}", Slugger::new().slug(&normalize_reference(reference))));
+
+                (reference.to_string(), url)
+            } else if let Some(url) = captures.name("url") {
+                (url.as_str().to_string(), url.as_str().to_string())
+            } else {
+                // [Unwrap] That's the only group left
+                let word = captures.name("word_reference").unwrap().as_str();
+
+                match self.target(word) {
+                    Some(url) => (word.to_string(), url),
+                    // Not a reference after all, just a word that ends in an underscore
+                    None => return self.push_text(output, &captures[0], html),
+                }
+            };
+
+        if !html {
+            output.push_str(&text);
+            return;
+        }
+
+        output.push_str("<a href=\"");
+        // [Unwrap] Writing to a String should not fail
+        escape_href(&mut *output, &self.resolve_link(&url)).unwrap();
+        output.push_str("\">");
+        escape_html(&mut *output, &text).unwrap();
+        output.push_str("</a>");
+    }
+
+    fn push_text(&self, output: &mut String, text: &str, html: bool) {
+        if html {
+            // [Unwrap] Writing to a String should not fail
+            escape_html(&mut *output, text).unwrap();
+        } else {
+            output.push_str(text);
+        }
+    }
+
+    fn target(&self, name: &str) -> Option<String> {
+        self.targets.get(&normalize_reference(name)).cloned()
+    }
+
+    fn image(&mut self, uri: &str, options: &HashMap<&str, &str>) -> String {
+        if let Some(path) = local_path(uri, &self.re_url_scheme) {
+            self.local_images.insert(markdown::local_file_path(&self.root_dir, path));
+        }
+
+        let mut output = String::from("<img src=\"");
+        // [Unwrap] Writing to a String should not fail
+        escape_href(&mut output, &self.resolve_url(uri)).unwrap();
+        output.push_str("\" alt=\"");
+        escape_html(&mut output, options.get("alt").copied().unwrap_or_default()).unwrap();
+        output.push('"');
+
+        for attribute in ["width", "height"] {
+            if let Some(value) = options.get(attribute) {
+                output.push_str(&format!(" {}=\"", attribute));
+                escape_html(&mut output, value).unwrap();
+                output.push('"');
+            }
+        }
+
+        output.push_str(" />");
+        output
+    }
+
+    /// Links are resolved like images, unless they're meant to be kept relative.
+    ///
+    fn resolve_link(&self, url: &str) -> String {
+        if self.resolve_links { self.resolve_url(url) } else { url.to_string() }
+    }
+
+    /// Relative URLs are turned into `file://` ones in the document's directory, the same way the
+    /// markdown renderer does it.
+    ///
+    fn resolve_url(&self, url: &str) -> String {
+        match local_path(url, &self.re_url_scheme) {
+            Some(path) => format!("file://{}/{}", self.root_dir.display(), path),
+            None => url.to_string(),
+        }
+    }
+}
+
+/// Add a `data-source-line` attribute to the HTML tag that starts at `position` in the output,
+/// right after the tag's name.
+///
+pub(crate) fn add_source_line(output: &mut String, position: usize, line: usize) {
+    let name_end = output[position..].
+        strip_prefix('<').
+        and_then(|tag| tag.find(|c: char| c.is_whitespace() || c == '>' || c == '/'));
+
+    if let Some(name_end) = name_end {
+        output.insert_str(position + 1 + name_end, &format!(" data-source-line=\"{}\"", line));
+    }
+}
+
+/// Remove the backslashes that escape characters in the contents of inline markup, keeping the
+/// characters themselves.
+///
+fn unescape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => output.extend(chars.next()),
+            c    => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// The kinds of list items, along with what's needed to tell whether the next item belongs to the
+/// same list.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bullet(char),
+    // The number of the item, or None for auto-numbered `#.` items
+    Enumerated(Option<usize>),
+}
+
+impl ListKind {
+    fn continues_with(&self, other: &ListKind) -> bool {
+        match (self, other) {
+            (ListKind::Bullet(a), ListKind::Bullet(b)) => a == b,
+            (ListKind::Enumerated(_), ListKind::Enumerated(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// If the line starts a list item, get its kind and the byte offset where its content starts.
+///
+fn list_marker(line: &str) -> Option<(ListKind, usize)> {
+    let marker_end = line.find(' ').unwrap_or(line.len());
+    let marker = &line[..marker_end];
+    let content_start = marker_end + line[marker_end..].len() - line[marker_end..].trim_start().len();
+
+    let kind = match marker {
+        "-" | "*" | "+" => ListKind::Bullet(marker.chars().next()?),
+        _ => {
+            let number = marker.strip_prefix('(').and_then(|m| m.strip_suffix(')')).
+                or_else(|| marker.strip_suffix('.')).
+                or_else(|| marker.strip_suffix(')'))?;
+
+            if number == "#" {
+                ListKind::Enumerated(None)
+            } else {
+                ListKind::Enumerated(Some(number.parse().ok()?))
+            }
+        },
+    };
+
+    Some((kind, content_start))
+}
+
+/// If the line consists of a single repeated punctuation character, like the ones that underline
+/// section titles, return that character.
+///
+fn adornment(line: &str) -> Option<char> {
+    let mut chars = line.chars();
+    let first = chars.next().filter(|c| ADORNMENT_CHARS.contains(*c))?;
+
+    if line.len() >= 2 && chars.all(|c| c == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+fn is_directive_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == ':')
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Collect the indented lines starting at `start`, including blank lines between them, and
+/// dedent them. Returns the lines and the index of the line after the last one.
+///
+fn indented_block<'a>(lines: &[&'a str], start: usize) -> (Vec<&'a str>, usize) {
+    let mut end = start;
+    for (index, line) in lines.iter().enumerate().skip(start) {
+        if !line.is_empty() && indentation(line) == 0 {
+            break;
+        }
+        if !line.is_empty() {
+            end = index + 1;
+        }
+    }
+
+    let block = &lines[start..end];
+    let min_indent = block.iter().filter(|l| !l.is_empty()).map(|l| indentation(l)).min().unwrap_or(0);
+    let dedented = block.iter().map(|l| l.get(min_indent..).unwrap_or("")).collect();
+
+    (dedented, end)
+}
+
+/// The path of a relative URL in the document's directory, `None` for fragments and URLs with a
+/// scheme.
+///
+pub(crate) fn local_path<'a>(url: &'a str, re_url_scheme: &Regex) -> Option<&'a str> {
+    if url.starts_with('#') || re_url_scheme.is_match(url) {
+        return None;
+    }
+
+    Some(url.strip_prefix("./").or_else(|| url.strip_prefix('/')).unwrap_or(url))
+}
+
+/// Reference names are case-insensitive and whitespace-neutral.
+///
+fn normalize_reference(name: &str) -> String {
+    let name = name.trim_matches('`');
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
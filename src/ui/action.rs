@@ -49,6 +49,24 @@ pub enum Action {
 
     /// Show a help popup. Default: `F1`
     ShowHelp,
+
+    /// Print the rendered page into a PDF file next to the markdown file. Default: `ctrl+p`
+    ExportPdf,
+
+    /// Switch to the next tab. Default: `ctrl+Page_Down`
+    NextTab,
+    /// Switch to the previous tab. Default: `ctrl+Page_Up`
+    PreviousTab,
+    /// Close the current tab, quitting if it's the last one. Default: `ctrl+w`
+    CloseTab,
+
+    /// Go back to the previously opened document in the tab. Default: `alt+Left`
+    GoBack,
+    /// Go forward to the next opened document in the tab. Default: `alt+Right`
+    GoForward,
+
+    /// Show or hide an outline of the document's headings. Default: `o`
+    ToggleOutline,
 }
 
 impl Default for Action {
@@ -88,6 +106,17 @@ impl Default for Keymaps {
         keymaps.set_action(ModifierType::empty(), keys::constants::equal, Action::ZoomReset);
         // F1 to show help popup
         keymaps.set_action(ModifierType::empty(), keys::constants::F1, Action::ShowHelp);
+        // Ctrl+P to export to PDF
+        keymaps.set_action(ModifierType::CONTROL_MASK, keys::constants::p, Action::ExportPdf);
+        // Ctrl+PageDown/PageUp to switch tabs, Ctrl+W to close them
+        keymaps.set_action(ModifierType::CONTROL_MASK, keys::constants::Page_Down, Action::NextTab);
+        keymaps.set_action(ModifierType::CONTROL_MASK, keys::constants::Page_Up, Action::PreviousTab);
+        keymaps.set_action(ModifierType::CONTROL_MASK, keys::constants::w, Action::CloseTab);
+        // Alt+Left/Right to move through history
+        keymaps.set_action(ModifierType::MOD1_MASK, keys::constants::Left, Action::GoBack);
+        keymaps.set_action(ModifierType::MOD1_MASK, keys::constants::Right, Action::GoForward);
+        // o to toggle the outline of headings
+        keymaps.set_action(ModifierType::empty(), keys::constants::o, Action::ToggleOutline);
 
         keymaps
     }
@@ -1,16 +1,21 @@
 //! A container for the `Browser` struct that wraps the [`webkit2gtk::WebView`].
 
+use std::path::{PathBuf, Path};
 use std::time::Instant;
 
 use anyhow::anyhow;
 use gio::Cancellable;
 use gtk::prelude::*;
 use log::{debug, warn};
-use webkit2gtk::traits::WebViewExt;
-use webkit2gtk::{WebContext, WebView};
+use webkit2gtk::traits::{
+    NavigationPolicyDecisionExt, PolicyDecisionExt, PrintOperationExt, URIRequestExt, WebViewExt,
+};
+use webkit2gtk::{
+    LoadEvent, NavigationPolicyDecision, PolicyDecisionType, PrintOperation, WebContext, WebView,
+};
 
 use crate::assets::PageState;
-use crate::input::Config;
+use crate::input::{self, Config};
 
 /// A thin layer on top of [`webkit2gtk::WebView`] to put helper methods into.
 ///
@@ -32,10 +37,11 @@ impl Browser {
         Ok(Browser { webview, config })
     }
 
-    /// Add this browser instance's webview to the given GTK Window.
+    /// Add this browser instance's webview to the given GTK container, taking up all the
+    /// available space.
     ///
-    pub fn attach_to(&self, window: &gtk::Window) {
-        window.add(&self.webview);
+    pub fn attach_to(&self, container: &gtk::Box) {
+        container.pack_start(&self.webview, true, true, 0);
     }
 
     /// Delegates to [`webkit2gtk::WebView`]
@@ -43,9 +49,83 @@ impl Browser {
         self.webview.load_uri(uri);
     }
 
-    /// Delegates to [`webkit2gtk::WebView`]
+    /// Reload the page, bypassing the cache, so changed images and styles are shown.
+    ///
     pub fn reload(&self) {
-        self.webview.reload();
+        self.webview.reload_bypass_cache();
+    }
+
+    /// Invoke the given callback every time a page is fully loaded into the webview.
+    ///
+    pub fn connect_load_finished<F: Fn() + 'static>(&self, callback: F) {
+        self.webview.connect_load_changed(move |_webview, event| {
+            if event == LoadEvent::Finished {
+                callback();
+            }
+        });
+    }
+
+    /// Intercept navigation to local documents, so they can be rendered instead of being opened as
+    /// plain text. The callback is given the path of the file and the `#fragment` of the link, if
+    /// there is one. Other navigation goes through as usual.
+    ///
+    pub fn connect_markdown_link<F: Fn(PathBuf, Option<String>) + 'static>(&self, callback: F) {
+        self.webview.connect_decide_policy(move |_webview, decision, decision_type| {
+            if decision_type != PolicyDecisionType::NavigationAction {
+                return false;
+            }
+
+            let uri = decision.downcast_ref::<NavigationPolicyDecision>().
+                and_then(|d| d.navigation_action()).
+                and_then(|a| a.request()).
+                and_then(|r| r.uri());
+
+            match uri.as_deref().and_then(parse_markdown_uri) {
+                Some((path, fragment)) => {
+                    debug!("Following link to document: {}", path.display());
+                    decision.ignore();
+                    callback(path, fragment);
+                    true
+                },
+                None => false,
+            }
+        });
+    }
+
+    /// Print the current page into a PDF file at the given (absolute) path, without showing a
+    /// print dialog. The `on_finish` callback is invoked when printing is done, whether it
+    /// succeeded or not.
+    ///
+    pub fn print_to_pdf<F: Fn() + 'static>(&self, pdf_path: &Path, on_finish: F) {
+        let output_uri = match glib::filename_to_uri(pdf_path, None) {
+            Ok(uri) => uri,
+            Err(e) => {
+                warn!("Couldn't print to {}: {}", pdf_path.display(), e);
+                on_finish();
+                return;
+            }
+        };
+
+        let print_settings = gtk::PrintSettings::new();
+        print_settings.set_printer("Print to File");
+        print_settings.set(*gtk::PRINT_SETTINGS_OUTPUT_FILE_FORMAT, Some("pdf"));
+        print_settings.set(*gtk::PRINT_SETTINGS_OUTPUT_URI, Some(&output_uri));
+
+        let print_operation = PrintOperation::new(&self.webview);
+        print_operation.set_print_settings(&print_settings);
+
+        let path_string = pdf_path.display().to_string();
+        print_operation.connect_failed(move |_operation, error| {
+            warn!("Couldn't print to {}: {}", path_string, error);
+        });
+
+        let path_string = pdf_path.display().to_string();
+        print_operation.connect_finished(move |_operation| {
+            debug!("Printing finished: {}", path_string);
+            on_finish();
+        });
+
+        print_operation.print();
     }
 
     /// Increase zoom level by ~10%
@@ -67,6 +147,14 @@ impl Browser {
         }
     }
 
+    /// Set the zoom level to the given value, 1.0 being 100%. Going below 20% or so is not allowed.
+    ///
+    pub fn set_zoom(&self, zoom_level: f64) {
+        let zoom_level = zoom_level.max(0.2);
+        self.webview.set_zoom_level(zoom_level);
+        debug!("Zoom level set to: {}", zoom_level);
+    }
+
     /// Reset to the base zoom level defined in the config (which defaults to 100%).
     ///
     pub fn zoom_reset(&self) {
@@ -92,15 +180,38 @@ impl Browser {
     /// Execute some (async) javascript code in the webview, without checking the result other than
     /// printing a warning if it errors out.
     ///
-    pub fn execute_js(&self, js_code: &'static str) {
+    pub fn execute_js(&self, js_code: &str) {
         let now = Instant::now();
+        let js_code_clone = js_code.to_string();
 
         self.webview.run_javascript(js_code, None::<&Cancellable>, move |result| {
             if let Err(e) = result {
                 warn!("Javascript execution error: {}", e);
             } else {
-                debug!("Javascript executed in {}ms:\n> {}", now.elapsed().as_millis(), js_code);
+                debug!("Javascript executed in {}ms:\n> {}", now.elapsed().as_millis(), js_code_clone);
             }
         });
     }
 }
+
+/// If the URI points to a local document, extract its path and the fragment after the `#`.
+///
+fn parse_markdown_uri(uri: &str) -> Option<(PathBuf, Option<String>)> {
+    if !uri.starts_with("file://") {
+        return None;
+    }
+
+    let (uri, fragment) = match uri.split_once('#') {
+        Some((uri, fragment)) => (uri, Some(fragment.to_string())),
+        None => (uri, None),
+    };
+    let uri = uri.split('?').next().unwrap_or(uri);
+
+    let (path, _hostname) = glib::filename_from_uri(uri).ok()?;
+
+    if input::is_document_file(&path) {
+        Some((path, fragment))
+    } else {
+        None
+    }
+}
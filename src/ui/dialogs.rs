@@ -4,14 +4,14 @@ use std::path::PathBuf;
 
 use gtk::prelude::*;
 
-use crate::input::Config;
+use crate::input::{Config, ASCIIDOC_EXTENSIONS, MARKDOWN_EXTENSIONS, RST_EXTENSIONS};
 
 /// A popup to choose a file if it wasn't provided on the command-line.
 ///
 pub struct FilePicker(gtk::FileChooserDialog);
 
 impl FilePicker {
-    /// Construct a new file picker that only shows markdown files by default
+    /// Construct a new file picker that only shows documents it can render by default
     ///
     pub fn new() -> FilePicker {
         let dialog = gtk::FileChooserDialog::new(
@@ -20,11 +20,15 @@ impl FilePicker {
             gtk::FileChooserAction::Open,
         );
 
-        // Only show markdown files
+        // Only show markdown, reStructuredText and AsciiDoc files
         let filter = gtk::FileFilter::new();
-        filter.set_name(Some("Markdown files (*.md, *.markdown)"));
-        filter.add_pattern("*.md");
-        filter.add_pattern("*.markdown");
+        filter.set_name(Some("Documents (*.md, *.markdown, *.rst, *.rest, *.adoc, *.asciidoc)"));
+        let extensions = MARKDOWN_EXTENSIONS.iter().
+            chain(RST_EXTENSIONS).
+            chain(ASCIIDOC_EXTENSIONS);
+        for extension in extensions {
+            filter.add_pattern(&format!("*.{}", extension));
+        }
         dialog.add_filter(&filter);
 
         // Just in case, allow showing all files
@@ -0,0 +1,85 @@
+//! Navigation history between documents shown in the same tab.
+
+use std::path::{PathBuf, Path};
+
+use crate::assets::PageState;
+
+/// A list of visited documents, with a current position that can move back and forward, the way
+/// a browser's history works.
+///
+#[derive(Debug, Clone)]
+pub struct History {
+    back: Vec<HistoryEntry>,
+    current: HistoryEntry,
+    forward: Vec<HistoryEntry>,
+}
+
+/// A single visited document, along with the state of the page when it was left.
+///
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// The markdown file.
+    pub path: PathBuf,
+
+    /// The scroll position and other client-side state to restore when coming back to it.
+    pub page_state: PageState,
+}
+
+impl History {
+    /// Create a history that only contains the given starting document.
+    ///
+    pub fn new(start: PathBuf) -> Self {
+        let current = HistoryEntry { path: start, page_state: PageState::default() };
+        History { back: Vec::new(), current, forward: Vec::new() }
+    }
+
+    /// The document at the current position.
+    ///
+    pub fn current(&self) -> &Path {
+        &self.current.path
+    }
+
+    /// Navigate to a new document, storing the given `page_state` for the one we're leaving.
+    /// Anything that was "forward" of the current position is discarded.
+    ///
+    pub fn push(&mut self, path: PathBuf, page_state: PageState) {
+        let next = HistoryEntry { path, page_state: PageState::default() };
+        let mut previous = std::mem::replace(&mut self.current, next);
+        previous.page_state = page_state;
+
+        self.back.push(previous);
+        self.forward.clear();
+    }
+
+    /// Undo a [`History::push`], dropping the current document and going back to the previous
+    /// one, with its page state. Returns the entry we've moved to, or `None` if we're at the start.
+    ///
+    pub fn pop(&mut self) -> Option<&HistoryEntry> {
+        self.current = self.back.pop()?;
+        Some(&self.current)
+    }
+
+    /// Move one step back, storing the given `page_state` for the document we're leaving. Returns
+    /// the entry we've moved to, or `None` if we're at the start.
+    ///
+    pub fn go_back(&mut self, page_state: PageState) -> Option<&HistoryEntry> {
+        let previous = self.back.pop()?;
+        let mut current = std::mem::replace(&mut self.current, previous);
+        current.page_state = page_state;
+
+        self.forward.push(current);
+        Some(&self.current)
+    }
+
+    /// Move one step forward, storing the given `page_state` for the document we're leaving.
+    /// Returns the entry we've moved to, or `None` if we're at the end.
+    ///
+    pub fn go_forward(&mut self, page_state: PageState) -> Option<&HistoryEntry> {
+        let next = self.forward.pop()?;
+        let mut current = std::mem::replace(&mut self.current, next);
+        current.page_state = page_state;
+
+        self.back.push(current);
+        Some(&self.current)
+    }
+}
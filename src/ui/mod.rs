@@ -3,79 +3,158 @@
 pub mod action;
 pub mod browser;
 pub mod dialogs;
+pub mod history;
+pub mod outline;
+pub mod sidebar;
+pub mod tab;
 
+use std::cell::{Cell, RefCell};
 use std::path::{PathBuf, Path};
 use std::process::Command;
+use std::rc::Rc;
+use std::sync::mpsc;
 
+use anyhow::anyhow;
 use gtk::prelude::*;
 use log::{debug, warn, error};
-use pathbuftools::PathBufTools;
 
 use crate::assets::Assets;
+use crate::control::ControlServer;
 use crate::input::{InputFile, Config};
 use crate::markdown::RenderedContent;
 use crate::ui::action::{Action, Keymaps};
-use crate::ui::browser::Browser;
 use crate::ui::dialogs::open_help_dialog;
+use crate::ui::sidebar::Sidebar;
+use crate::ui::tab::Tab;
 
-/// The container for all the GTK widgets of the app -- window, webview, etc.
-/// All of these are reference-counted, so should be cheap to clone.
+/// The container for all the GTK widgets of the app -- window, tabs with webviews, an optional
+/// sidebar, etc. All of these are reference-counted, so should be cheap to clone.
 ///
 #[derive(Clone)]
 pub struct App {
     window: gtk::Window,
-    browser: Browser,
-    assets: Assets,
-    filename: PathBuf,
+    paned: gtk::Paned,
+    notebook: gtk::Notebook,
+    tabs: Rc<RefCell<Vec<Tab>>>,
+    sidebar: Rc<RefCell<Option<Sidebar>>>,
+    control_server: Rc<RefCell<Option<ControlServer>>>,
     config: Config,
 }
 
 impl App {
-    /// Construct a new app. Input params:
+    /// Construct a new app with no open documents. Tabs for them are added with
+    /// [`App::add_tab`].
     ///
-    /// - input_file: Used as the window title and for other actions on the file.
-    /// - assets:     Encapsulates the HTML layout that will be wrapping the rendered markdown.
-    ///
-    /// Initialization could fail due to a `WebContext` failure.
-    ///
-    pub fn init(config: Config, input_file: InputFile, assets: Assets) -> anyhow::Result<Self> {
+    pub fn init(config: Config) -> anyhow::Result<Self> {
         let window = gtk::Window::new(gtk::WindowType::Toplevel);
         window.set_default_size(1024, 768);
+        window.set_title("Quickmd");
+
+        let notebook = gtk::Notebook::new();
+        notebook.set_show_tabs(false);
+        notebook.set_scrollable(true);
+
+        // The left side is only filled in if there's a sidebar
+        let paned = gtk::Paned::new(gtk::Orientation::Horizontal);
+        paned.pack2(&notebook, true, false);
+        window.add(&paned);
+
+        let tabs: Rc<RefCell<Vec<Tab>>> = Rc::new(RefCell::new(Vec::new()));
+
+        // Keep the window title in sync with the active tab:
+        let window_clone = window.clone();
+        let tabs_clone = tabs.clone();
+        notebook.connect_switch_page(move |_notebook, page, _page_num| {
+            let tabs = tabs_clone.borrow();
+            if let Some(tab) = tabs.iter().find(|t| t.widget().upcast_ref::<gtk::Widget>() == page) {
+                window_clone.set_title(&tab.title());
+            }
+        });
+
+        let sidebar = Rc::new(RefCell::new(None));
+        let control_server = Rc::new(RefCell::new(None));
 
-        if let Ok(asset_path) = assets.output_path() {
-            if let Ok(icon) = gdk_pixbuf::Pixbuf::from_file(asset_path.join("icon.png")) {
-                window.set_icon(Some(&icon));
+        Ok(App { window, paned, notebook, tabs, sidebar, control_server, config })
+    }
+
+    /// Open a new tab for the given file, render it and switch to it. Input params:
+    ///
+    /// - input_file: Used as the tab's label, the window title and for other actions on the file.
+    /// - assets:     Encapsulates the HTML layout that will be wrapping the rendered markdown.
+    /// - watch:      Whether to rerender the file when it changes.
+    ///
+    /// Could fail due to a `WebContext` failure, or if the file can't be rendered.
+    ///
+    pub fn add_tab(&self, input_file: InputFile, assets: Assets, watch: bool) -> anyhow::Result<Tab> {
+        if self.window.icon().is_none() {
+            if let Ok(asset_path) = assets.output_path() {
+                if let Ok(icon) = gdk_pixbuf::Pixbuf::from_file(asset_path.join("icon.png")) {
+                    self.window.set_icon(Some(&icon));
+                }
             }
         }
 
-        let title = match &input_file {
-            InputFile::Filesystem(p) => format!("{} - Quickmd", p.short_path().display()),
-            InputFile::Stdin(_)      => String::from("Quickmd"),
-        };
-        window.set_title(&title);
+        let tab = Tab::new(self.config.clone(), input_file, assets, watch)?;
+        tab.open()?;
 
-        let browser = Browser::new(config.clone())?;
-        browser.attach_to(&window);
+        tab.widget().show_all();
+        self.tabs.borrow_mut().push(tab.clone());
+        let page_num = self.notebook.append_page(tab.widget(), Some(tab.label()));
 
-        Ok(App { window, browser, assets, config, filename: input_file.path().to_path_buf() })
+        self.notebook.set_show_tabs(self.notebook.n_pages() > 1);
+        self.notebook.set_current_page(Some(page_num));
+        self.window.set_title(&tab.title());
+
+        Ok(tab)
     }
 
-    /// Start listening to events from the `ui_receiver` and trigger the relevant methods on the
-    /// `App`. Doesn't block.
+    /// Show a sidebar with all the documents under `root_dir`. Selecting one of them renders
+    /// it in the current tab. If `watch` is set, files that are added or removed later show up in
+    /// the list.
     ///
-    pub fn init_render_loop(&self, ui_receiver: glib::Receiver<Event>) {
-        let mut app_clone = self.clone();
+    pub fn add_sidebar(&self, root_dir: PathBuf, watch: bool) {
+        let sidebar = Sidebar::new(root_dir);
+
+        let app = self.clone();
+        sidebar.connect_file_selected(move |path| {
+            if let Some(tab) = app.current_tab() {
+                tab.navigate_to(path, None).
+                    unwrap_or_else(|e| warn!("Couldn't open file: {}", e));
+            }
+        });
 
+        if watch {
+            sidebar.watch();
+        }
+
+        self.paned.pack1(sidebar.widget(), false, false);
+        *self.sidebar.borrow_mut() = Some(sidebar);
+    }
+
+    /// Listen for commands from other processes on the socket at `socket_path`, see
+    /// [`crate::control`]. Files opened through it get a new tab, watched for changes if `watch`
+    /// is set. Other commands apply to the current tab.
+    ///
+    pub fn listen(&self, socket_path: PathBuf, watch: bool) -> anyhow::Result<()> {
+        let (ui_sender, ui_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+
+        let app = self.clone();
         ui_receiver.attach(None, move |event| {
             match event {
-                Event::LoadHtml(content) => {
-                    app_clone.load_content(&content).
-                        unwrap_or_else(|e| warn!("Couldn't update HTML: {}", e))
+                Event::Remote(event, reply) => {
+                    let result = app.handle_remote_event(*event, watch);
+                    // The client might have stopped waiting, nothing to do about that
+                    let _ = reply.send(result.map_err(|e| e.to_string()));
                 },
-                Event::Reload => app_clone.reload(),
+                _ => debug!("Ignored event from the control socket: {:?}", event),
             }
             glib::Continue(true)
         });
+
+        let control_server = ControlServer::start(socket_path, ui_sender)?;
+        *self.control_server.borrow_mut() = Some(control_server);
+
+        Ok(())
     }
 
     /// Actually start the UI, blocking the main thread.
@@ -86,40 +165,172 @@ impl App {
 
         gtk::main();
 
-        self.assets.clean_up();
+        self.clean_up();
     }
 
-    fn load_content(&mut self, content: &RenderedContent) -> anyhow::Result<()> {
-        let page_state = self.browser.get_page_state();
-        let output_path = self.assets.build(content, &page_state)?;
+    /// Instead of showing the UI, wait for the pages to load, print each of them to a PDF file
+    /// next to its markdown file and quit. Blocks the main thread until then.
+    ///
+    pub fn run_pdf_export(&mut self) {
+        let pending = Rc::new(Cell::new(self.tabs.borrow().len()));
+
+        for tab in self.tabs.borrow().iter() {
+            let browser  = tab.browser().clone();
+            let pdf_path = tab.pdf_path();
+            let pending  = pending.clone();
+
+            tab.browser().connect_load_finished(move || {
+                debug!("Exporting to PDF: {}", pdf_path.display());
+
+                let pending = pending.clone();
+                browser.print_to_pdf(&pdf_path, move || {
+                    pending.set(pending.get() - 1);
+                    if pending.get() == 0 {
+                        gtk::main_quit();
+                    }
+                });
+            });
+        }
 
-        debug!("Loading HTML:");
-        debug!(" > output_path = {}", output_path.display());
+        gtk::main();
+
+        self.clean_up();
+    }
+
+    fn handle_remote_event(&self, event: Event, watch: bool) -> anyhow::Result<()> {
+        if let Event::OpenFile(path) = event {
+            return self.open_file(path, watch);
+        }
+
+        let tab = self.current_tab().ok_or_else(|| anyhow!("No document is open"))?;
+
+        match event {
+            Event::Reload              => tab.open()?,
+            Event::ScrollToLine(line)  => tab.scroll_to_line(line),
+            Event::ScrollToAnchor(id)  => tab.scroll_to_anchor(&id),
+            Event::SetZoom(zoom_level) => tab.browser().set_zoom(zoom_level),
+            Event::Action(action)      => self.run_action(&tab, action),
+            _ => debug!("Ignored remote event: {:?}", event),
+        }
 
-        self.browser.load_uri(&format!("file://{}", output_path.display()));
         Ok(())
     }
 
-    fn reload(&self) {
-        self.browser.reload();
+    /// Open the given file in a new tab, or switch to the tab it's already open in. Either way,
+    /// the window is brought to the front, since the request came from somewhere else.
+    ///
+    fn open_file(&self, path: PathBuf, watch: bool) -> anyhow::Result<()> {
+        if !path.exists() {
+            return Err(anyhow!("No such file: {}", path.display()));
+        }
+
+        let open_page = self.tabs.borrow().iter().position(|tab| is_same_file(&tab.filename(), &path));
+
+        if let Some(page_num) = open_page {
+            debug!("Switching to open file: {}", path.display());
+            self.notebook.set_current_page(Some(page_num as u32));
+        } else {
+            debug!("Opening file: {}", path.display());
+
+            let assets = Assets::init(None)?;
+            self.add_tab(InputFile::Filesystem(path), assets, watch)?;
+        }
+
+        self.window.present();
+        Ok(())
     }
 
-    fn connect_events(&self) {
-        let filename        = self.filename.clone();
-        let editor_command  = self.config.editor_command.clone();
+    /// Perform one of the actions that are triggered on key release.
+    ///
+    fn run_action(&self, tab: &Tab, action: Action) {
+        let browser = tab.browser();
+        let editor_command = &self.config.editor_command;
+
+        match action {
+            Action::LaunchEditor => {
+                debug!("Launching an editor");
+                launch_editor(editor_command, &tab.filename());
+            },
+            Action::ExecEditor => {
+                debug!("Exec-ing into an editor");
+                exec_editor(editor_command, &tab.filename());
+            },
+            Action::ZoomIn    => browser.zoom_in(),
+            Action::ZoomOut   => browser.zoom_out(),
+            Action::ZoomReset => browser.zoom_reset(),
+            Action::ShowHelp  => { open_help_dialog(&self.window); },
+            Action::ExportPdf => {
+                debug!("Exporting to PDF: {}", tab.pdf_path().display());
+                browser.print_to_pdf(&tab.pdf_path(), || ());
+            },
+            Action::CloseTab  => self.close_current_tab(),
+            Action::GoBack    => {
+                tab.go_back().unwrap_or_else(|e| warn!("Couldn't go back: {}", e));
+            },
+            Action::GoForward => {
+                tab.go_forward().unwrap_or_else(|e| warn!("Couldn't go forward: {}", e));
+            },
+            Action::ToggleOutline => tab.toggle_outline(),
+            Action::Quit      => gtk::main_quit(),
+            _ => (),
+        }
+    }
+
+    fn current_tab(&self) -> Option<Tab> {
+        let page_num = self.notebook.current_page()?;
+        self.tabs.borrow().get(page_num as usize).cloned()
+    }
+
+    /// Close the currently active tab. Closing the last one quits the app.
+    ///
+    fn close_current_tab(&self) {
+        let page_num = match self.notebook.current_page() {
+            Some(page_num) => page_num,
+            None => return,
+        };
+
+        let mut tab = self.tabs.borrow_mut().remove(page_num as usize);
+        tab.close();
+        self.notebook.remove_page(Some(page_num));
+
+        if self.notebook.n_pages() == 0 {
+            gtk::main_quit();
+        } else {
+            self.notebook.set_show_tabs(self.notebook.n_pages() > 1);
+        }
+    }
+
+    fn clean_up(&self) {
+        for tab in self.tabs.borrow_mut().iter_mut() {
+            tab.close();
+        }
+        if let Some(sidebar) = self.sidebar.borrow().as_ref() {
+            sidebar.close();
+        }
+        if let Some(control_server) = self.control_server.borrow().as_ref() {
+            control_server.stop();
+        }
+    }
 
+    fn connect_events(&self) {
         let mut keymaps = Keymaps::default();
         keymaps.add_config_mappings(&self.config.mappings).unwrap_or_else(|e| {
             error!("Mapping parsing error: {}", e);
         });
 
         // Key presses mapped to repeatable events:
-        let browser = self.browser.clone();
+        let app = self.clone();
         let keymaps_clone = keymaps.clone();
         self.window.connect_key_press_event(move |_window, event| {
             let keyval   = event.keyval();
             let keystate = event.state();
 
+            let tab = match app.current_tab() {
+                Some(tab) => tab,
+                None => return Inhibit(false),
+            };
+            let browser = tab.browser();
+
             match keymaps_clone.get_action(keystate, keyval) {
                 Action::SmallScrollDown => browser.execute_js("window.scrollBy(0, 70)"),
                 Action::BigScrollDown   => browser.execute_js("window.scrollBy(0, 250)"),
@@ -129,45 +340,46 @@ impl App {
                 Action::ScrollToBottom  => {
                     browser.execute_js("window.scroll({top: document.body.scrollHeight})")
                 },
+                // The notebook has its own handling of some of these keys, so we stop the event
+                Action::NextTab => {
+                    app.notebook.next_page();
+                    return Inhibit(true);
+                },
+                Action::PreviousTab => {
+                    app.notebook.prev_page();
+                    return Inhibit(true);
+                },
                 _ => (),
             }
             Inhibit(false)
         });
 
         // Key releases mapped to one-time events:
-        let browser = self.browser.clone();
+        let app = self.clone();
         let keymaps_clone = keymaps.clone();
-        self.window.connect_key_release_event(move |window, event| {
+        self.window.connect_key_release_event(move |_window, event| {
             let keyval   = event.keyval();
             let keystate = event.state();
 
-            match keymaps_clone.get_action(keystate, keyval) {
-                Action::LaunchEditor => {
-                    debug!("Launching an editor");
-                    launch_editor(&editor_command, &filename);
-                },
-                Action::ExecEditor => {
-                    debug!("Exec-ing into an editor");
-                    exec_editor(&editor_command, &filename);
-                },
-                Action::ZoomIn    => browser.zoom_in(),
-                Action::ZoomOut   => browser.zoom_out(),
-                Action::ZoomReset => browser.zoom_reset(),
-                Action::ShowHelp  => { open_help_dialog(window); },
-                Action::Quit      => gtk::main_quit(),
-                _ => (),
-            }
+            let tab = match app.current_tab() {
+                Some(tab) => tab,
+                None => return Inhibit(false),
+            };
+
+            app.run_action(&tab, keymaps_clone.get_action(keystate, keyval));
             Inhibit(false)
         });
 
         // On Ctrl+Scroll, zoom:
-        let browser = self.browser.clone();
+        let app = self.clone();
         self.window.connect_scroll_event(move |_window, event| {
             if event.state().contains(gdk::ModifierType::CONTROL_MASK) {
-                match event.direction() {
-                    gdk::ScrollDirection::Up   => browser.zoom_in(),
-                    gdk::ScrollDirection::Down => browser.zoom_out(),
-                    _ => (),
+                if let Some(tab) = app.current_tab() {
+                    match event.direction() {
+                        gdk::ScrollDirection::Up   => tab.browser().zoom_in(),
+                        gdk::ScrollDirection::Down => tab.browser().zoom_out(),
+                        _ => (),
+                    }
                 }
             }
 
@@ -186,10 +398,33 @@ impl App {
 #[derive(Debug)]
 pub enum Event {
     /// Load the given content into the webview.
-    LoadHtml(RenderedContent),
+    LoadHtml(Box<RenderedContent>),
 
     /// Refresh the webview.
     Reload,
+
+    /// Rebuild the list of files in the directory sidebar.
+    RefreshFiles,
+
+    /// Open the given file in a new tab.
+    OpenFile(PathBuf),
+
+    /// Scroll the webview to the element with the given id.
+    ScrollToAnchor(String),
+
+    /// Set the zoom level of the webview, 1.0 being 100%.
+    SetZoom(f64),
+
+    /// Perform an action, as if its key was pressed.
+    Action(Action),
+
+    /// Scroll the webview to the block that starts on the given line of the document, or the
+    /// closest one before it.
+    ScrollToLine(usize),
+
+    /// An event from the control socket, along with a channel to send back the result of handling
+    /// it, or an error message.
+    Remote(Box<Event>, mpsc::Sender<Result<(), String>>),
 }
 
 #[cfg(target_family="unix")]
@@ -208,6 +443,13 @@ fn exec_editor(_editor_command: &[String], _filename_string: &Path) {
     warn!("Not on a UNIX system, can't exec to a text editor");
 }
 
+fn is_same_file(left: &Path, right: &Path) -> bool {
+    match (left.canonicalize(), right.canonicalize()) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => left == right,
+    }
+}
+
 fn launch_editor(editor_command: &[String], file_path: &Path) {
     if let Some(mut editor) = build_editor_command(editor_command, file_path) {
         if let Err(e) = editor.spawn() {
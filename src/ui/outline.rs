@@ -0,0 +1,110 @@
+//! An outline of the headings of the rendered document, shown next to it.
+
+use gtk::prelude::*;
+
+use crate::markdown::Heading;
+
+const TEXT_COLUMN: u32 = 0;
+const ID_COLUMN: u32 = 1;
+
+/// A tree of the document's headings, with each one nested under the closest previous heading of
+/// a higher level. Hidden until toggled. Reference-counted, so should be cheap to clone.
+///
+#[derive(Clone)]
+pub struct Outline {
+    container: gtk::ScrolledWindow,
+    tree_view: gtk::TreeView,
+    store: gtk::TreeStore,
+}
+
+impl Outline {
+    /// Construct an empty, hidden outline.
+    ///
+    pub fn new() -> Self {
+        // Columns: the heading text and the id of its element
+        let store = gtk::TreeStore::new(&[glib::Type::STRING, glib::Type::STRING]);
+
+        let tree_view = gtk::TreeView::with_model(&store);
+        tree_view.set_headers_visible(false);
+        tree_view.set_activate_on_single_click(true);
+
+        let column = gtk::TreeViewColumn::new();
+        let cell = gtk::CellRendererText::new();
+        column.pack_start(&cell, true);
+        column.add_attribute(&cell, "text", TEXT_COLUMN as i32);
+        tree_view.append_column(&column);
+
+        let container = gtk::ScrolledWindow::new(gtk::Adjustment::NONE, gtk::Adjustment::NONE);
+        container.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Automatic);
+        container.set_size_request(240, -1);
+        container.add(&tree_view);
+
+        // Only shown when toggled, so a `show_all` on the parent shouldn't affect it
+        tree_view.show();
+        container.set_no_show_all(true);
+
+        Outline { container, tree_view, store }
+    }
+
+    /// The top-level widget of the outline.
+    ///
+    pub fn widget(&self) -> &gtk::ScrolledWindow {
+        &self.container
+    }
+
+    /// Show the outline if it's hidden, hide it if it's shown.
+    ///
+    pub fn toggle(&self) {
+        self.container.set_visible(!self.container.is_visible());
+    }
+
+    /// Call the given callback with the id of a heading whenever it gets clicked.
+    ///
+    pub fn connect_heading_activated<F>(&self, callback: F)
+        where F: Fn(String) + 'static
+    {
+        self.tree_view.connect_row_activated(move |tree_view, path, _column| {
+            let model = match tree_view.model() {
+                Some(model) => model,
+                None => return,
+            };
+
+            if let Some(iter) = model.iter(path) {
+                if let Ok(id) = model.value(&iter, ID_COLUMN as i32).get::<String>() {
+                    callback(id);
+                }
+            }
+        });
+    }
+
+    /// Rebuild the tree from the given headings.
+    ///
+    pub fn update(&self, headings: &[Heading]) {
+        self.store.clear();
+
+        // The currently open headings that later ones could be nested under
+        let mut parents: Vec<(u32, gtk::TreeIter)> = Vec::new();
+
+        for heading in headings {
+            while parents.last().is_some_and(|(level, _)| *level >= heading.level) {
+                parents.pop();
+            }
+
+            let parent = parents.last().map(|(_, iter)| iter);
+            let iter = self.store.insert_with_values(parent, None, &[
+                (TEXT_COLUMN, &heading.text),
+                (ID_COLUMN, &heading.id),
+            ]);
+
+            parents.push((heading.level, iter));
+        }
+
+        self.tree_view.expand_all();
+    }
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
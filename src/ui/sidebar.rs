@@ -0,0 +1,154 @@
+//! A list of the documents in a directory, shown next to the rendered one.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{PathBuf, Path};
+use std::rc::Rc;
+
+use gtk::prelude::*;
+
+use crate::background::{self, UpdateLoop};
+use crate::input;
+use crate::ui::Event;
+
+const NAME_COLUMN: u32 = 0;
+const PATH_COLUMN: u32 = 1;
+
+/// A tree of all the documents under a root directory, with a node for each subdirectory
+/// that contains any. Reference-counted, so should be cheap to clone.
+///
+#[derive(Clone)]
+pub struct Sidebar {
+    container: gtk::ScrolledWindow,
+    tree_view: gtk::TreeView,
+    store: gtk::TreeStore,
+    root_dir: PathBuf,
+    update_loop: Rc<RefCell<Option<UpdateLoop>>>,
+}
+
+impl Sidebar {
+    /// Construct a sidebar, filled in with the documents currently in `root_dir`.
+    ///
+    pub fn new(root_dir: PathBuf) -> Self {
+        // Columns: the name to show and the full path to open, empty for directories
+        let store = gtk::TreeStore::new(&[glib::Type::STRING, glib::Type::STRING]);
+
+        let tree_view = gtk::TreeView::with_model(&store);
+        tree_view.set_headers_visible(false);
+
+        let column = gtk::TreeViewColumn::new();
+        let cell = gtk::CellRendererText::new();
+        column.pack_start(&cell, true);
+        column.add_attribute(&cell, "text", NAME_COLUMN as i32);
+        tree_view.append_column(&column);
+
+        let container = gtk::ScrolledWindow::new(gtk::Adjustment::NONE, gtk::Adjustment::NONE);
+        container.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Automatic);
+        container.set_size_request(220, -1);
+        container.add(&tree_view);
+
+        let sidebar = Sidebar {
+            container, tree_view, store, root_dir,
+            update_loop: Rc::new(RefCell::new(None)),
+        };
+        sidebar.refresh();
+        sidebar
+    }
+
+    /// The top-level widget of the sidebar.
+    ///
+    pub fn widget(&self) -> &gtk::ScrolledWindow {
+        &self.container
+    }
+
+    /// Call the given callback with the path of a document whenever it gets selected.
+    /// Selecting a directory does nothing.
+    ///
+    pub fn connect_file_selected<F>(&self, callback: F)
+        where F: Fn(PathBuf) + 'static
+    {
+        self.tree_view.selection().connect_changed(move |selection| {
+            if let Some((model, iter)) = selection.selected() {
+                match model.value(&iter, PATH_COLUMN as i32).get::<String>() {
+                    Ok(path) if !path.is_empty() => callback(PathBuf::from(path)),
+                    _ => (),
+                }
+            }
+        });
+    }
+
+    /// Start watching the root directory, so the list of files is rebuilt when one is added or
+    /// removed anywhere in the tree.
+    ///
+    pub fn watch(&self) {
+        let (ui_sender, ui_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+
+        let sidebar = self.clone();
+        ui_receiver.attach(None, move |event| {
+            if let Event::RefreshFiles = event {
+                sidebar.refresh();
+            }
+            glib::Continue(true)
+        });
+
+        let update_loop = background::init_directory_loop(self.root_dir.clone(), ui_sender);
+        *self.update_loop.borrow_mut() = Some(update_loop);
+    }
+
+    /// Stop watching the root directory, if we were.
+    ///
+    pub fn close(&self) {
+        if let Some(update_loop) = self.update_loop.borrow_mut().take() {
+            update_loop.stop();
+        }
+    }
+
+    /// Rebuild the tree from the documents currently in the root directory.
+    ///
+    pub fn refresh(&self) {
+        self.store.clear();
+
+        let mut dir_iters = HashMap::new();
+
+        for path in input::find_documents(&self.root_dir) {
+            let relative_path = path.strip_prefix(&self.root_dir).unwrap_or(&path);
+            let parent = self.dir_iter(relative_path.parent(), &mut dir_iters);
+
+            self.store.insert_with_values(parent.as_ref(), None, &[
+                (NAME_COLUMN, &file_name(relative_path)),
+                (PATH_COLUMN, &path.to_string_lossy().into_owned()),
+            ]);
+        }
+
+        self.tree_view.expand_all();
+    }
+
+    /// Find the node of the given directory relative to the root, creating it and any of its
+    /// parents that don't exist yet. Files right under the root don't have a parent node.
+    ///
+    fn dir_iter(
+        &self,
+        dir: Option<&Path>,
+        dir_iters: &mut HashMap<PathBuf, gtk::TreeIter>,
+    ) -> Option<gtk::TreeIter> {
+        let dir = dir.filter(|d| *d != Path::new(""))?;
+
+        if let Some(iter) = dir_iters.get(dir) {
+            return Some(iter.clone());
+        }
+
+        let parent = self.dir_iter(dir.parent(), dir_iters);
+        let iter = self.store.insert_with_values(parent.as_ref(), None, &[
+            (NAME_COLUMN, &file_name(dir)),
+            (PATH_COLUMN, &String::new()),
+        ]);
+
+        dir_iters.insert(dir.to_path_buf(), iter.clone());
+        Some(iter)
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().into_owned()).
+        unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,472 @@
+//! A single document shown in the app, with its own webview and assets.
+
+use std::cell::RefCell;
+use std::io;
+use std::path::{PathBuf, Path};
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use gtk::prelude::*;
+use log::{debug, warn};
+use pathbuftools::PathBufTools;
+
+use crate::assets::{Assets, PageState};
+use crate::background::{self, UpdateLoop};
+use crate::input::{InputFile, Config};
+use crate::markdown::{self, RenderedContent};
+use crate::render::{self, Render};
+use crate::ui::Event;
+use crate::ui::browser::Browser;
+use crate::ui::history::{History, HistoryEntry};
+use crate::ui::outline::Outline;
+
+/// The contents of a single notebook tab -- the webview that renders a markdown file, an outline of
+/// its headings and the assets used to build its HTML. Following a link to another local markdown
+/// file opens it in the same tab. Reference-counted, so should be cheap to clone.
+///
+#[derive(Clone)]
+pub struct Tab {
+    container: gtk::Box,
+    label: gtk::Label,
+    browser: Browser,
+    outline: Outline,
+    assets: Assets,
+    input_file: InputFile,
+    config: Config,
+    watch: bool,
+    state: Rc<RefCell<TabState>>,
+}
+
+/// The parts of a tab that change as different documents are opened in it.
+///
+struct TabState {
+    history: History,
+    render_source: Option<glib::SourceId>,
+    update_loop: Option<UpdateLoop>,
+    next_page_state: Option<PageState>,
+    next_fragment: Option<String>,
+    next_source_line: Option<usize>,
+    page_loaded: bool,
+    stream_source: Option<glib::SourceId>,
+    stream_loop: Option<UpdateLoop>,
+    stream_content: Option<Box<RenderedContent>>,
+}
+
+impl Tab {
+    /// Construct a new tab. Input params:
+    ///
+    /// - config:     Used to configure the webview and the rendering of documents.
+    /// - input_file: The file that's initially rendered in this tab. Used for the tab's title and
+    ///               for other actions on the file.
+    /// - assets:     Encapsulates the HTML layout that will be wrapping the rendered markdown.
+    /// - watch:      Whether to rerender documents when they change. Never done for STDIN, unless
+    ///               it's a stream, which is always rerendered.
+    ///
+    /// Nothing is rendered until [`Tab::open`] is called. Initialization could fail due to a
+    /// `WebContext` failure.
+    ///
+    pub fn new(config: Config, input_file: InputFile, assets: Assets, watch: bool) -> anyhow::Result<Self> {
+        let container = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+        let label = gtk::Label::new(None);
+
+        let browser = Browser::new(config.clone())?;
+        browser.attach_to(&container);
+
+        let outline = Outline::new();
+        container.pack_end(outline.widget(), false, false, 0);
+
+        let state = Rc::new(RefCell::new(TabState {
+            history:          History::new(input_file.path().to_path_buf()),
+            render_source:    None,
+            update_loop:      None,
+            next_page_state:  None,
+            next_fragment:    None,
+            next_source_line: None,
+            page_loaded:      false,
+            stream_source:    None,
+            stream_loop:      None,
+            stream_content:   None,
+        }));
+
+        let tab = Tab { container, label, browser, outline, assets, input_file, config, watch, state };
+        tab.label.set_text(&tab.label_text());
+
+        let tab_clone = tab.clone();
+        tab.browser.connect_markdown_link(move |path, fragment| {
+            tab_clone.follow_link(path, fragment).
+                unwrap_or_else(|e| warn!("Couldn't open linked file: {}", e));
+        });
+
+        let tab_clone = tab.clone();
+        tab.outline.connect_heading_activated(move |id| tab_clone.scroll_to_anchor(&id));
+
+        // A line to scroll to might have been requested before the page was ready for it
+        let tab_clone = tab.clone();
+        tab.browser.connect_load_finished(move || {
+            let line = {
+                let mut state = tab_clone.state.borrow_mut();
+                state.page_loaded = true;
+                state.next_source_line.take()
+            };
+            if let Some(line) = line {
+                tab_clone.scroll_to_line(line);
+            }
+        });
+
+        Ok(tab)
+    }
+
+    /// Render the current document and start watching it for changes. Any previous render loop
+    /// and file watcher are stopped, but only once the document has been rendered, so they keep
+    /// going if it fails.
+    ///
+    pub fn open(&self) -> anyhow::Result<()> {
+        if self.input_file.is_stream() && self.is_stdin() {
+            return self.open_stream();
+        }
+
+        let md_path = self.filename();
+
+        if !md_path.exists() {
+            return Err(anyhow!("File not found: {}", md_path.display()));
+        }
+
+        // Initial render
+        let renderer = render::renderer_for(md_path.clone(), &self.config);
+        let content = renderer.run()?;
+
+        self.stop();
+
+        let (ui_sender, ui_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+        self.init_render_loop(ui_receiver);
+
+        let local_images = content.local_images.clone();
+        ui_sender.send(Event::LoadHtml(Box::new(content)))?;
+
+        if self.watch && !self.is_stdin() {
+            let update_loop = background::init_update_loop(renderer, local_images, ui_sender);
+            self.state.borrow_mut().update_loop = Some(update_loop);
+        }
+
+        self.label.set_text(&self.label_text());
+        self.update_window_title();
+
+        Ok(())
+    }
+
+    /// The top-level widget of the tab, to be added to the notebook.
+    ///
+    pub fn widget(&self) -> &gtk::Box {
+        &self.container
+    }
+
+    /// The widget to show as the tab's label in the notebook.
+    ///
+    pub fn label(&self) -> &gtk::Label {
+        &self.label
+    }
+
+    /// The webview the document is rendered in.
+    ///
+    pub fn browser(&self) -> &Browser {
+        &self.browser
+    }
+
+    /// The path of the markdown file currently rendered in this tab.
+    ///
+    pub fn filename(&self) -> PathBuf {
+        self.state.borrow().history.current().to_path_buf()
+    }
+
+    /// The path that exporting to PDF writes to. The PDF goes next to the markdown file with the
+    /// same name. For STDIN, there's no such file, so it goes in the current directory.
+    ///
+    pub fn pdf_path(&self) -> PathBuf {
+        if self.is_stdin() {
+            return std::env::current_dir().unwrap_or_default().join("quickmd.pdf");
+        }
+
+        let md_path = self.filename();
+        md_path.canonicalize().unwrap_or(md_path).with_extension("pdf")
+    }
+
+    /// A title for the window while this tab is active.
+    ///
+    pub fn title(&self) -> String {
+        if self.is_stdin() {
+            String::from("Quickmd")
+        } else {
+            format!("{} - Quickmd", self.filename().short_path().display())
+        }
+    }
+
+    /// Show or hide the outline of the document's headings.
+    ///
+    pub fn toggle_outline(&self) {
+        self.outline.toggle();
+    }
+
+    /// Open the previous document in the tab's history, if there is one, restoring its scroll
+    /// position.
+    ///
+    pub fn go_back(&self) -> anyhow::Result<()> {
+        self.step_through_history(History::go_back, History::go_forward)
+    }
+
+    /// Open the next document in the tab's history, if there is one, restoring its scroll
+    /// position.
+    ///
+    pub fn go_forward(&self) -> anyhow::Result<()> {
+        self.step_through_history(History::go_forward, History::go_back)
+    }
+
+    /// Move through the history with `step` and open the document we end up on. If it can't be
+    /// opened, like when it's been deleted, `undo_step` moves back to the document that's still
+    /// shown.
+    ///
+    fn step_through_history<F, U>(&self, step: F, undo_step: U) -> anyhow::Result<()>
+        where F: Fn(&mut History, PageState) -> Option<&HistoryEntry>,
+              U: Fn(&mut History, PageState) -> Option<&HistoryEntry>
+    {
+        let page_state = self.browser.get_page_state();
+        let entry = {
+            let mut state = self.state.borrow_mut();
+            let entry = match step(&mut state.history, page_state) {
+                Some(entry) => entry.clone(),
+                None => return Ok(()),
+            };
+            state.next_page_state = Some(entry.page_state.clone());
+            entry
+        };
+
+        self.open().map_err(|e| {
+            let mut state = self.state.borrow_mut();
+            undo_step(&mut state.history, entry.page_state);
+            state.next_page_state = None;
+            e
+        })
+    }
+
+    /// Render a different markdown file in this tab, adding it to the tab's history. If a
+    /// `fragment` is given, the page is scrolled to the element with that id. If the file can't be
+    /// rendered, it's removed from the history again and the current document stays open.
+    ///
+    pub fn navigate_to(&self, path: PathBuf, fragment: Option<String>) -> anyhow::Result<()> {
+        if path == self.filename() && fragment.is_none() {
+            return Ok(());
+        }
+
+        if !path.exists() {
+            return Err(anyhow!("File not found: {}", path.display()));
+        }
+
+        let page_state = self.browser.get_page_state();
+        {
+            let mut state = self.state.borrow_mut();
+            state.history.push(path, page_state);
+            state.next_page_state = Some(PageState::default());
+            state.next_fragment = fragment;
+        }
+
+        self.open().map_err(|e| {
+            let mut state = self.state.borrow_mut();
+            state.history.pop();
+            state.next_page_state = None;
+            state.next_fragment = None;
+            e
+        })
+    }
+
+    /// Scroll to the block that starts on the given line of the document, or the closest one before
+    /// it. If the page is still loading, it's scrolled once it's done.
+    ///
+    pub fn scroll_to_line(&self, line: usize) {
+        {
+            let mut state = self.state.borrow_mut();
+            if !state.page_loaded {
+                state.next_source_line = Some(line);
+                return;
+            }
+        }
+
+        self.browser.execute_js(&format!("scroll_to_line({})", line));
+    }
+
+    /// Scroll to the element with the given id, like the anchor of a heading. In sanitized HTML,
+    /// that's the element with the id prefixed with `user-content-`.
+    ///
+    pub fn scroll_to_anchor(&self, id: &str) {
+        // [Unwrap] Serializing a string should not fail
+        let id_literal = serde_json::to_string(id).unwrap();
+        self.browser.execute_js(&format!("scroll_to_anchor({})", id_literal));
+    }
+
+    /// Stop processing render events and delete the temporary assets of the tab.
+    ///
+    pub fn close(&mut self) {
+        self.stop();
+        self.stop_stream();
+        self.assets.clean_up();
+    }
+
+    fn follow_link(&self, path: PathBuf, fragment: Option<String>) -> anyhow::Result<()> {
+        // Links in the markdown are already rendered as absolute paths, but relative ones in inline
+        // HTML are resolved against the generated HTML file, so they need to be moved over to the
+        // markdown file's directory.
+        let path = match self.assets.output_path().map(|o| path.strip_prefix(o).map(Path::to_path_buf)) {
+            Ok(Ok(relative_path)) => {
+                self.filename().parent().unwrap_or_else(|| Path::new("")).join(relative_path)
+            },
+            _ => path,
+        };
+
+        debug!("Opening linked file: {}", path.display());
+        self.navigate_to(path, fragment)
+    }
+
+    /// Show the latest snapshot from a stream on STDIN, rendering new ones as they arrive. The
+    /// stream is read from the first time it's opened until the tab is closed, even while other
+    /// documents are shown in its place, so going back to it shows its current state.
+    ///
+    fn open_stream(&self) -> anyhow::Result<()> {
+        self.stop();
+
+        if self.state.borrow().stream_source.is_none() {
+            let renderer = markdown::Renderer::new(self.filename(), &self.config).with_source_lines();
+            let (ui_sender, ui_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+
+            let mut tab_clone = self.clone();
+            let source_id = ui_receiver.attach(None, move |event| {
+                if let Event::LoadHtml(content) = event {
+                    tab_clone.update_stream(content);
+                }
+                glib::Continue(true)
+            });
+
+            let stream_loop = background::init_stream_loop(io::stdin(), renderer, ui_sender);
+
+            let mut state = self.state.borrow_mut();
+            state.stream_source = Some(source_id);
+            state.stream_loop = Some(stream_loop);
+        }
+
+        let content = self.state.borrow_mut().stream_content.take();
+        if let Some(content) = content {
+            self.clone().update_stream(content);
+        }
+
+        self.label.set_text(&self.label_text());
+        self.update_window_title();
+
+        Ok(())
+    }
+
+    /// Keep the latest snapshot of the stream, showing it if the stream is what's currently open.
+    ///
+    fn update_stream(&mut self, content: Box<RenderedContent>) {
+        if self.is_stdin() {
+            self.load_content(&content).
+                unwrap_or_else(|e| warn!("Couldn't update HTML: {}", e));
+        }
+        self.state.borrow_mut().stream_content = Some(content);
+    }
+
+    fn init_render_loop(&self, ui_receiver: glib::Receiver<Event>) {
+        let mut tab_clone = self.clone();
+
+        let source_id = ui_receiver.attach(None, move |event| {
+            match event {
+                Event::LoadHtml(content) => {
+                    tab_clone.load_content(&content).
+                        unwrap_or_else(|e| warn!("Couldn't update HTML: {}", e))
+                },
+                Event::Reload => tab_clone.reload(),
+                Event::ScrollToLine(line) => tab_clone.scroll_to_line(line),
+                Event::ScrollToAnchor(id) => tab_clone.scroll_to_anchor(&id),
+                Event::SetZoom(zoom_level) => tab_clone.browser.set_zoom(zoom_level),
+                // Only meant for the app or the directory sidebar
+                Event::OpenFile(_) | Event::Action(_) | Event::RefreshFiles | Event::Remote(..) => (),
+            }
+            glib::Continue(true)
+        });
+
+        self.state.borrow_mut().render_source = Some(source_id);
+    }
+
+    fn load_content(&mut self, content: &RenderedContent) -> anyhow::Result<()> {
+        let (page_state, fragment) = {
+            let mut state = self.state.borrow_mut();
+            (state.next_page_state.take(), state.next_fragment.take())
+        };
+        let page_state = page_state.unwrap_or_else(|| self.browser.get_page_state());
+        let output_path = self.assets.build(content, &page_state)?;
+        self.outline.update(&content.headings);
+
+        debug!("Loading HTML:");
+        debug!(" > output_path = {}", output_path.display());
+
+        let mut uri = format!("file://{}", output_path.display());
+        if let Some(fragment) = fragment {
+            uri = format!("{}#{}", uri, fragment);
+        }
+
+        self.state.borrow_mut().page_loaded = false;
+        self.browser.load_uri(&uri);
+        Ok(())
+    }
+
+    fn reload(&self) {
+        self.state.borrow_mut().page_loaded = false;
+        self.browser.reload();
+    }
+
+    fn stop(&self) {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(source_id) = state.render_source.take() {
+            source_id.remove();
+        }
+        if let Some(update_loop) = state.update_loop.take() {
+            update_loop.stop();
+        }
+    }
+
+    fn stop_stream(&self) {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(source_id) = state.stream_source.take() {
+            source_id.remove();
+        }
+        if let Some(stream_loop) = state.stream_loop.take() {
+            stream_loop.stop();
+        }
+    }
+
+    fn is_stdin(&self) -> bool {
+        !self.input_file.is_real_file() && self.filename() == self.input_file.path()
+    }
+
+    fn label_text(&self) -> String {
+        if self.is_stdin() {
+            return String::from("STDIN");
+        }
+
+        let md_path = self.filename();
+        md_path.file_name().map(|n| n.to_string_lossy().into_owned()).
+            unwrap_or_else(|| md_path.display().to_string())
+    }
+
+    /// If this is the active tab, the notebook keeps it visible, so we can update the title of
+    /// the window it's in.
+    ///
+    fn update_window_title(&self) {
+        if !self.container.is_child_visible() {
+            return;
+        }
+
+        let window = self.container.toplevel().and_then(|w| w.downcast::<gtk::Window>().ok());
+        if let Some(window) = window {
+            window.set_title(&self.title());
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use std::io::Write;
+
+use tempfile::Builder;
+use quickmd::asciidoc::Renderer;
+use quickmd::input::Config;
+use quickmd::markdown::Heading;
+use quickmd::render;
+
+fn adoc_file(contents: &str) -> tempfile::NamedTempFile {
+    let mut file = Builder::new().suffix(".adoc").tempfile().unwrap();
+    write!(file, "{}", contents).unwrap();
+    file
+}
+
+fn render(contents: &str) -> String {
+    let file = adoc_file(contents);
+    Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap().html
+}
+
+#[test]
+fn test_renderer_is_picked_by_extension() {
+    let file = adoc_file("= Title\n");
+    let renderer = render::renderer_for(file.path().to_path_buf(), &Config::default());
+
+    assert_eq!(renderer.run().unwrap().html, "<h1 data-source-line=\"1\" id=\"title\">Title</h1>\n");
+}
+
+#[test]
+fn test_export_renderer_keeps_relative_links() {
+    let file = adoc_file("See link:docs/guide.adoc[the guide].\n");
+    let renderer = render::export_renderer_for(file.path().to_path_buf(), &Config::default());
+
+    assert_eq!(renderer.run().unwrap().html, "<p>See <a href=\"docs/guide.adoc\">the guide</a>.</p>\n");
+}
+
+#[test]
+fn test_renders_sections() {
+    let file = adoc_file(concat!(
+        "= Title\n",
+        "Author Name\n",
+        ":toc:\n",
+        "\n",
+        "== Section *one*\n",
+        "\n",
+        "[[custom]]\n",
+        "=== Subsection\n",
+        "\n",
+        "## Section two\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        "<h1 id=\"title\">Title</h1>\n",
+        "<h2 id=\"section-one\">Section <strong>one</strong></h2>\n",
+        "<h3 id=\"custom\">Subsection</h3>\n",
+        "<h2 id=\"section-two\">Section two</h2>\n",
+    ));
+    assert_eq!(content.headings, vec![
+        Heading { level: 1, id: String::from("title"),       text: String::from("Title") },
+        Heading { level: 2, id: String::from("section-one"), text: String::from("Section one") },
+        Heading { level: 3, id: String::from("custom"),      text: String::from("Subsection") },
+        Heading { level: 2, id: String::from("section-two"), text: String::from("Section two") },
+    ]);
+}
+
+#[test]
+fn test_renders_inline_markup() {
+    let html = render(concat!(
+        "Some *bold*, _italic_, `code` and #marked# text, with ``uncon``strained **b**old.\n",
+        "Not bold: 2 * 3 * 4, snake_case_name, \\*escaped*.\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<p>Some <strong>bold</strong>, <em>italic</em>, <code>code</code> and <mark>marked</mark> ",
+        "text, with <code>uncon</code>strained <strong>b</strong>old.\n",
+        "Not bold: 2 * 3 * 4, snake_case_name, *escaped*.</p>\n",
+    ));
+}
+
+#[test]
+fn test_replaces_attribute_references() {
+    let html = render(concat!(
+        ":project: QuickMD\n",
+        ":url: https://github.com/AndrewRadev/quickmd\n",
+        "\n",
+        "{project} lives at {url}[GitHub]. Not {unknown}, not \\{project}.\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<p>QuickMD lives at <a href=\"https://github.com/AndrewRadev/quickmd\">GitHub</a>. ",
+        "Not {unknown}, not {project}.</p>\n",
+    ));
+}
+
+#[test]
+fn test_renders_links_and_cross_references() {
+    let file = adoc_file(concat!(
+        "See https://example.com, https://example.com/docs[the docs], ",
+        "link:other.adoc[another file^] and <<section-two,the second section>>.\n",
+    ));
+    let root = file.path().parent().unwrap().canonicalize().unwrap();
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, format!(concat!(
+        "<p>See <a href=\"https://example.com\">https://example.com</a>, ",
+        "<a href=\"https://example.com/docs\">the docs</a>, ",
+        "<a href=\"file://{}/other.adoc\">another file</a> and ",
+        "<a href=\"#section-two\">the second section</a>.</p>\n",
+    ), root.display()));
+}
+
+#[test]
+fn test_renders_lists() {
+    let html = render(concat!(
+        "* One\n",
+        "continued\n",
+        "** Nested\n",
+        "* Two\n",
+        "\n",
+        "// Lists with different markers need a comment between them\n",
+        ". First\n",
+        ". Second\n",
+        "\n",
+        "//\n",
+        "* [x] Done\n",
+        "* [ ] Not done\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<ul>\n",
+        "<li>One\ncontinued\n",
+        "<ul>\n<li>Nested</li>\n</ul>\n",
+        "</li>\n",
+        "<li>Two</li>\n",
+        "</ul>\n",
+        "<ol>\n<li>First</li>\n<li>Second</li>\n</ol>\n",
+        "<ul>\n",
+        "<li><input disabled=\"\" type=\"checkbox\" checked=\"\"/>\nDone</li>\n",
+        "<li><input disabled=\"\" type=\"checkbox\"/>\nNot done</li>\n",
+        "</ul>\n",
+    ));
+}
+
+#[test]
+fn test_attaches_blocks_to_list_items() {
+    let html = render(concat!(
+        "* Install it:\n",
+        "+\n",
+        "----\n",
+        "cargo install quickmd\n",
+        "----\n",
+        "* Run it\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<ul>\n",
+        "<li>Install it:\n<pre><code>cargo install quickmd\n</code></pre>\n</li>\n",
+        "<li>Run it</li>\n",
+        "</ul>\n",
+    ));
+}
+
+#[test]
+fn test_renders_description_lists() {
+    let html = render(concat!(
+        "CPU:: The brain\n",
+        "RAM::\n",
+        "  The memory\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<dl>\n",
+        "<dt>CPU</dt>\n<dd>The brain</dd>\n",
+        "<dt>RAM</dt>\n<dd>The memory</dd>\n",
+        "</dl>\n",
+    ));
+}
+
+#[test]
+fn test_renders_code_blocks() {
+    let file = adoc_file(concat!(
+        "[source,unknown-language]\n",
+        "----\n",
+        "a < b\n",
+        "----\n",
+        "\n",
+        "....\n",
+        "literal *text*\n",
+        "....\n",
+        "\n",
+        "  indented\n",
+        "  text\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        "<pre><code class=\"language-unknown-language\">a &lt; b\n</code></pre>\n",
+        "<pre><code>literal *text*\n</code></pre>\n",
+        "<pre><code>indented\ntext\n</code></pre>\n",
+    ));
+    assert!(content.code_languages.contains("unknown-language"));
+}
+
+#[test]
+fn test_highlights_known_languages() {
+    let html = render("[source,rust]\n----\nfn main() {}\n----\n");
+
+    assert!(html.starts_with("<pre"));
+    assert!(html.contains("<span"));
+}
+
+#[test]
+fn test_renders_admonitions() {
+    let html = render(concat!(
+        "NOTE: Take a note.\n",
+        "\n",
+        "[WARNING]\n",
+        "====\n",
+        "Careful.\n",
+        "====\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<div class=\"markdown-alert markdown-alert-note\">\n",
+        "<p class=\"markdown-alert-title\">Note</p>\n",
+        "<p>Take a note.</p>\n",
+        "</div>\n",
+        "<div class=\"markdown-alert markdown-alert-warning\">\n",
+        "<p class=\"markdown-alert-title\">Warning</p>\n",
+        "<p>Careful.</p>\n",
+        "</div>\n",
+    ));
+}
+
+#[test]
+fn test_renders_quotes_and_block_titles() {
+    let html = render(concat!(
+        ".A quote\n",
+        "____\n",
+        "Quoted text\n",
+        "____\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<p><strong>A quote</strong></p>\n",
+        "<blockquote>\n<p>Quoted text</p>\n</blockquote>\n",
+    ));
+}
+
+#[test]
+fn test_renders_tables() {
+    let html = render(concat!(
+        "|===\n",
+        "|Name |Value\n",
+        "\n",
+        "|one |1\n",
+        "|two\n",
+        "|2\n",
+        "|===\n",
+    ));
+
+    assert_eq!(html, concat!(
+        "<table><thead><tr><th>Name</th><th>Value</th></tr></thead><tbody>\n",
+        "<tr><td>one</td><td>1</td></tr>\n",
+        "<tr><td>two</td><td>2</td></tr>\n",
+        "</tbody></table>\n",
+    ));
+}
+
+#[test]
+fn test_renders_images() {
+    let file = adoc_file("image::images/logo.png[Logo,200]\n");
+    let root = file.path().parent().unwrap().canonicalize().unwrap();
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, format!(
+        "<p><img src=\"file://{}/images/logo.png\" alt=\"Logo\" width=\"200\" /></p>\n",
+        root.display(),
+    ));
+    assert!(content.local_images.contains(&root.join("images/logo.png")));
+}
+
+#[test]
+fn test_skips_comments_and_unknown_macros() {
+    let html = render(concat!(
+        "// A comment\n",
+        "////\n",
+        "A comment block\n",
+        "////\n",
+        "toc::[]\n",
+        "Text\n",
+    ));
+
+    assert_eq!(html, "<p>Text</p>\n");
+}
+
+#[test]
+fn test_adds_source_lines_to_top_level_blocks() {
+    let file = adoc_file(concat!(
+        "== Title\n",
+        "\n",
+        "// A comment\n",
+        "Paragraph\n",
+        "\n",
+        "[NOTE]\n",
+        "====\n",
+        "Nested\n",
+        "====\n",
+    ));
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default()).with_source_lines();
+
+    assert_eq!(renderer.run().unwrap().html, concat!(
+        "<h2 data-source-line=\"1\" id=\"title\">Title</h2>\n",
+        "<p data-source-line=\"4\">Paragraph</p>\n",
+        "<div data-source-line=\"6\" class=\"markdown-alert markdown-alert-note\">\n",
+        "<p class=\"markdown-alert-title\">Note</p>\n",
+        "<p>Nested</p>\n",
+        "</div>\n",
+    ));
+}
+
+#[test]
+fn test_sanitizes_html_if_configured() {
+    let file = adoc_file("++++\n<script>alert(1)</script><b>Bold</b>\n++++\n");
+    let config = Config { sanitize_html: true, ..Config::default() };
+
+    let html = Renderer::new(file.path().to_path_buf(), &config).run().unwrap().html;
+
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("<b>Bold</b>"));
+}
@@ -51,6 +51,81 @@ fn test_building_a_file_with_assets_includes_main_static_files() {
     assert!(fs::read_to_string(&path).unwrap().contains("main.css"));
 }
 
+#[test]
+fn test_building_a_file_with_code_uses_bundled_highlight_js() {
+    let assets = Assets::init(None).unwrap();
+    let content = RenderedContent {
+        html: String::from("<pre><code class=\"language-rust\">fn main() {}</code></pre>"),
+        code_languages: vec![String::from("rust")].into_iter().collect(),
+        ..RenderedContent::default()
+    };
+
+    let path = assets.build(&content, &PageState::default()).unwrap();
+    let html = fs::read_to_string(&path).unwrap();
+    let output_path = assets.output_path().unwrap();
+
+    assert_contains!(html, r#"<script src="highlight.min.js"></script>"#);
+    assert_contains!(html, r#"href="highlight-github.css""#);
+    assert!(!html.contains("cdnjs"));
+
+    assert!(output_path.join("highlight.min.js").exists());
+    assert!(output_path.join("highlight-github.css").exists());
+}
+
+#[test]
+fn test_building_a_file_with_diagrams_uses_bundled_mermaid_js() {
+    let assets = Assets::init(None).unwrap();
+    let output_path = assets.output_path().unwrap();
+
+    let path = assets.build(&RenderedContent::default(), &PageState::default()).unwrap();
+    let html = fs::read_to_string(&path).unwrap();
+    assert!(!html.contains("mermaid"));
+
+    let content = RenderedContent {
+        html: String::from(r#"<pre class="mermaid">graph TD; A--&gt;B;</pre>"#),
+        has_diagrams: true,
+        ..RenderedContent::default()
+    };
+
+    let path = assets.build(&content, &PageState::default()).unwrap();
+    let html = fs::read_to_string(&path).unwrap();
+
+    assert_contains!(html, r#"<script src="mermaid.min.js"></script>"#);
+    assert_contains!(html, "mermaid.initialize(");
+    assert!(output_path.join("mermaid.min.js").exists());
+}
+
+#[test]
+fn test_building_a_file_with_math_uses_bundled_katex() {
+    let assets = Assets::init(None).unwrap();
+    let output_path = assets.output_path().unwrap();
+
+    let path = assets.build(&RenderedContent::default(), &PageState::default()).unwrap();
+    let html = fs::read_to_string(&path).unwrap();
+    assert!(!html.contains("katex"));
+
+    let content = RenderedContent {
+        html: String::from(r#"<p><span class="math math-inline">$x^2$</span></p>"#),
+        has_math: true,
+        ..RenderedContent::default()
+    };
+
+    let path = assets.build(&content, &PageState::default()).unwrap();
+    let html = fs::read_to_string(&path).unwrap();
+
+    assert_contains!(html, r#"<script src="katex.min.js"></script>"#);
+    assert_contains!(html, "<script>render_math()</script>");
+    assert!(output_path.join("katex.min.js").exists());
+
+    let standalone_path = output_path.join("export.html");
+    Assets::build_standalone(&content, "export.md", &standalone_path).unwrap();
+    let html = fs::read_to_string(&standalone_path).unwrap();
+
+    assert_contains!(html, "KaTeX 0.16.4");
+    assert_contains!(html, "function render_math()");
+    assert!(!html.contains(r#"<script src="katex.min.js""#));
+}
+
 #[test]
 fn test_building_a_file_with_assets_includes_scroll_position_as_the_title() {
     let assets = Assets::init(None).unwrap();
@@ -102,3 +177,49 @@ fn test_will_fail_if_output_dir_is_not_a_dir() {
 
     assert_matches!(Assets::init(Some(path)), Err(_));
 }
+
+#[test]
+fn test_building_a_standalone_file_inlines_assets_and_local_images() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let image_path = tempdir.path().join("image with spaces.png");
+    let output_path = tempdir.path().join("export.html");
+    fs::write(&image_path, b"fake image").unwrap();
+
+    let content = RenderedContent {
+        html: format!(
+            r#"<h1>Example</h1><img src="file://{}/image%20with%20spaces.png" /><img src="file:///missing.png" />"#,
+            tempdir.path().display()
+        ),
+        ..RenderedContent::default()
+    };
+
+    Assets::build_standalone(&content, "<notes>.md", &output_path).unwrap();
+    let html = fs::read_to_string(&output_path).unwrap();
+
+    assert_contains!(html, "<title>&lt;notes&gt;.md</title>");
+    assert_contains!(html, "<h1>Example</h1>");
+    assert_contains!(html, r#"<img src="data:image/png;base64,ZmFrZSBpbWFnZQ==""#);
+    assert_contains!(html, r#"<img src="file:///missing.png""#);
+    assert_contains!(html, "page_state.scroll_top");
+    assert!(!html.contains(r#"<script src="main.js""#));
+    assert!(!html.contains(r#"href="main.css""#));
+}
+
+#[test]
+fn test_building_a_standalone_file_with_diagrams_inlines_mermaid_js() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let output_path = tempdir.path().join("export.html");
+
+    let content = RenderedContent {
+        html: String::from(r#"<pre class="mermaid">graph TD; A--&gt;B;</pre>"#),
+        has_diagrams: true,
+        ..RenderedContent::default()
+    };
+
+    Assets::build_standalone(&content, "export.md", &output_path).unwrap();
+    let html = fs::read_to_string(&output_path).unwrap();
+
+    assert_contains!(html, "mermaid.initialize(");
+    assert_contains!(html, "mermaidAPI");
+    assert!(!html.contains(r#"<script src="mermaid.min.js""#));
+}
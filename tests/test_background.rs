@@ -1,13 +1,17 @@
+use std::collections::HashSet;
 use std::fs;
+use std::io::{self, Write};
 use std::sync::mpsc;
 use std::sync::mpsc::RecvTimeoutError::Timeout as TimeoutError;
+use std::sync::mpsc::RecvTimeoutError::Disconnected;
 use std::time::Duration;
 
 use claim::assert_matches;
 
+use quickmd::input::Config;
 use quickmd::ui;
 use quickmd::markdown::Renderer;
-use quickmd::background::init_update_loop;
+use quickmd::background::{Sender, init_update_loop, init_directory_loop, init_stream_loop};
 
 #[test]
 fn test_update_loop_detects_file_updates() {
@@ -15,10 +19,10 @@ fn test_update_loop_detects_file_updates() {
     let path = tempdir.path().join("file.md");
 
     fs::write(&path, "# Test").unwrap();
-    let renderer = Renderer::new(path.clone());
+    let renderer = Renderer::new(path.clone(), &Config::default());
 
     let (sender, receiver) = mpsc::channel();
-    init_update_loop(renderer, sender);
+    init_update_loop(renderer, HashSet::new(), sender);
     // Wait for the watcher thread to get ready
     std::thread::sleep(Duration::from_millis(10));
 
@@ -39,10 +43,10 @@ fn test_update_loop_detects_file_creation() {
     let path = tempdir.path().join("file.md");
 
     fs::write(&path, "# Test").unwrap();
-    let renderer = Renderer::new(path.clone());
+    let renderer = Renderer::new(path.clone(), &Config::default());
 
     let (sender, receiver) = mpsc::channel();
-    init_update_loop(renderer, sender);
+    init_update_loop(renderer, HashSet::new(), sender);
     // Wait for the watcher thread to get ready
     std::thread::sleep(Duration::from_millis(10));
 
@@ -68,10 +72,10 @@ fn test_update_loop_ignores_unrelated_files() {
     fs::write(&path, "# Test").unwrap();
     fs::write(&other_path, "# Other").unwrap();
 
-    let renderer = Renderer::new(path.clone());
+    let renderer = Renderer::new(path.clone(), &Config::default());
 
     let (sender, receiver) = mpsc::channel();
-    init_update_loop(renderer, sender);
+    init_update_loop(renderer, HashSet::new(), sender);
     // Wait for the watcher thread to get ready
     std::thread::sleep(Duration::from_millis(10));
 
@@ -82,3 +86,163 @@ fn test_update_loop_ignores_unrelated_files() {
     let message = receiver.recv_timeout(Duration::from_millis(300));
     assert_matches!(message, Err(TimeoutError));
 }
+
+#[test]
+fn test_update_loop_can_be_stopped() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path().join("file.md");
+
+    fs::write(&path, "# Test").unwrap();
+    let renderer = Renderer::new(path.clone(), &Config::default());
+
+    let (sender, receiver) = mpsc::channel();
+    let update_loop = init_update_loop(renderer, HashSet::new(), sender);
+    // Wait for the watcher thread to get ready
+    std::thread::sleep(Duration::from_millis(10));
+
+    update_loop.stop();
+    fs::write(path, "# Changed").unwrap();
+
+    // The thread exits without sending anything
+    let message = receiver.recv_timeout(Duration::from_millis(1000));
+    assert_matches!(message, Err(Disconnected));
+}
+
+#[test]
+fn test_directory_loop_detects_new_markdown_files() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let nested_dir = tempdir.path().join("nested");
+    fs::create_dir(&nested_dir).unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    init_directory_loop(tempdir.path().to_path_buf(), sender);
+    // Wait for the watcher thread to get ready
+    std::thread::sleep(Duration::from_millis(10));
+
+    // Files that aren't markdown don't matter
+    fs::write(nested_dir.join("file.txt"), "Text").unwrap();
+
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Err(TimeoutError));
+
+    fs::write(nested_dir.join("file.md"), "# Test").unwrap();
+
+    // Expect RefreshFiles message
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Ok(ui::Event::RefreshFiles));
+}
+
+#[test]
+fn test_directory_loop_ignores_skipped_directories() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    init_directory_loop(tempdir.path().to_path_buf(), sender);
+    // Wait for the watcher thread to get ready
+    std::thread::sleep(Duration::from_millis(10));
+
+    // Build output and empty directories don't matter
+    fs::create_dir_all(tempdir.path().join("target/debug/build")).unwrap();
+    fs::write(tempdir.path().join("target/debug/output.md"), "# Output").unwrap();
+    fs::create_dir(tempdir.path().join("empty")).unwrap();
+
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Err(TimeoutError));
+}
+
+#[test]
+fn test_update_loop_detects_image_updates() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path().join("file.md");
+    let image_path = tempdir.path().join("images").join("diagram.png");
+
+    fs::create_dir(tempdir.path().join("images")).unwrap();
+    fs::write(&image_path, "").unwrap();
+    fs::write(&path, "![diagram](images/diagram.png)").unwrap();
+    let renderer = Renderer::new(path.clone(), &Config::default());
+
+    let local_images = renderer.run().unwrap().local_images;
+
+    let (sender, receiver) = mpsc::channel();
+    init_update_loop(renderer, local_images, sender);
+    // Wait for the watcher thread to get ready
+    std::thread::sleep(Duration::from_millis(10));
+
+    fs::write(&image_path, "changed").unwrap();
+
+    // Expect Reload message
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Ok(ui::Event::Reload));
+
+    // Expect no further message
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Err(TimeoutError));
+
+    // Once the image is gone from the document, changes to it don't matter
+    fs::write(&path, "# No images").unwrap();
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Ok(ui::Event::LoadHtml(_)));
+
+    fs::write(&image_path, "changed again").unwrap();
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Err(TimeoutError));
+}
+
+#[test]
+fn test_stream_loop_renders_each_snapshot() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let renderer = Renderer::new(tempdir.path().join("STDIN"), &Config::default());
+    let (stream, mut writer) = io::pipe().unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    init_stream_loop(stream, renderer, sender);
+
+    let html = |message| match message {
+        Ok(ui::Event::LoadHtml(content)) => content.html,
+        other => panic!("Expected LoadHtml, got: {:?}", other),
+    };
+
+    writer.write_all(b"# One\0").unwrap();
+    assert!(html(receiver.recv_timeout(Duration::from_millis(300))).contains("One</h1>"));
+    writer.write_all(b"# Two\0").unwrap();
+    assert!(html(receiver.recv_timeout(Duration::from_millis(300))).contains("Two</h1>"));
+    writer.write_all(b"\0").unwrap();
+    assert_eq!(html(receiver.recv_timeout(Duration::from_millis(300))), "");
+    writer.write_all(b"# Three").unwrap();
+    drop(writer);
+    assert!(html(receiver.recv_timeout(Duration::from_millis(300))).contains("Three</h1>"));
+
+    // The stream is over
+    let message = receiver.recv_timeout(Duration::from_millis(300));
+    assert_matches!(message, Err(Disconnected));
+}
+
+#[test]
+fn test_stream_loop_skips_snapshots_that_are_out_of_date() {
+    // Takes a while to deliver every event, so snapshots pile up in the meantime
+    struct SlowSender(mpsc::Sender<ui::Event>);
+
+    impl Sender for SlowSender {
+        fn send(&mut self, event: ui::Event) -> Result<(), mpsc::SendError<ui::Event>> {
+            std::thread::sleep(Duration::from_millis(100));
+            self.0.send(event)
+        }
+    }
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let renderer = Renderer::new(tempdir.path().join("STDIN"), &Config::default());
+    let stream = io::Cursor::new(b"# One\0# Two\0# Three\0# Four".to_vec());
+
+    let (sender, receiver) = mpsc::channel();
+    init_stream_loop(stream, renderer, SlowSender(sender));
+
+    let mut rendered = Vec::new();
+    while let Ok(ui::Event::LoadHtml(content)) = receiver.recv_timeout(Duration::from_millis(500)) {
+        rendered.push(content.html);
+    }
+
+    // The first snapshot might be rendered before the rest come in, but the last one always is
+    assert!(rendered.len() <= 2, "Rendered too many snapshots: {:?}", rendered);
+    assert!(rendered.last().unwrap().contains("Four</h1>"));
+    assert!(!rendered.iter().any(|html| html.contains("Two</h1>") || html.contains("Three</h1>")));
+}
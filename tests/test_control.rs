@@ -0,0 +1,93 @@
+use std::os::unix::net::UnixListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use claim::{assert_matches, assert_ok, assert_err};
+
+use quickmd::control::{self, Command, ControlServer};
+use quickmd::ui::{self, action::Action};
+
+#[test]
+fn test_parsing_commands() {
+    let command: Command = serde_json::from_str(r#"{"command": "open", "path": "/tmp/README.md"}"#).unwrap();
+    assert_eq!(command, Command::Open { path: "/tmp/README.md".into() });
+
+    let command: Command = serde_json::from_str(r#"{"command": "scroll_to_line", "line": 42}"#).unwrap();
+    assert_eq!(command, Command::ScrollToLine { line: 42 });
+
+    let command: Command = serde_json::from_str(r#"{"command": "action", "action": "ZoomIn"}"#).unwrap();
+    assert_eq!(command, Command::Action { action: Action::ZoomIn });
+
+    let command: Command = serde_json::from_str(r#"{"command": "quit"}"#).unwrap();
+    assert_matches!(ui::Event::from(command), ui::Event::Action(Action::Quit));
+
+    assert_err!(serde_json::from_str::<Command>(r#"{"command": "unknown"}"#));
+    assert_err!(serde_json::from_str::<Command>(r#"{"command": "scroll_to_line"}"#));
+}
+
+#[test]
+fn test_sending_commands_to_a_server() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let socket_path = tempdir.path().join("quickmd.sock");
+
+    let (sender, receiver) = mpsc::channel();
+    let server = ControlServer::start(socket_path.clone(), sender).unwrap();
+    assert!(control::is_listening(&socket_path));
+
+    // Stands in for the UI, which can't open any files
+    let ui = thread::spawn(move || {
+        let mut events = Vec::new();
+
+        while let Ok(ui::Event::Remote(event, reply)) = receiver.recv_timeout(Duration::from_millis(300)) {
+            let result = match *event {
+                ui::Event::OpenFile(_) => Err(String::from("No such file")),
+                _ => Ok(()),
+            };
+            reply.send(result).unwrap();
+            events.push(*event);
+        }
+
+        events
+    });
+
+    assert_ok!(control::send(&socket_path, &Command::ScrollToLine { line: 42 }));
+    assert_ok!(control::send(&socket_path, &Command::Reload));
+
+    let error = control::send(&socket_path, &Command::Open { path: "/missing.md".into() }).unwrap_err();
+    assert_eq!(error.to_string(), "Command failed: No such file");
+
+    let events = ui.join().unwrap();
+    assert_matches!(events.as_slice(), [
+        ui::Event::ScrollToLine(42),
+        ui::Event::Reload,
+        ui::Event::OpenFile(_),
+    ]);
+
+    server.stop();
+    assert!(!socket_path.exists());
+    assert!(!control::is_listening(&socket_path));
+    assert_err!(control::send(&socket_path, &Command::Reload));
+}
+
+#[test]
+fn test_only_one_server_per_socket() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let socket_path = tempdir.path().join("quickmd.sock");
+
+    let (sender, _receiver) = mpsc::channel();
+    let server = ControlServer::start(socket_path.clone(), sender.clone()).unwrap();
+    assert_err!(ControlServer::start(socket_path.clone(), sender.clone()));
+
+    // A leftover socket file is replaced
+    server.stop();
+    drop(UnixListener::bind(&socket_path).unwrap());
+    assert!(socket_path.exists());
+    let server = ControlServer::start(socket_path.clone(), sender.clone()).unwrap();
+    server.stop();
+
+    // Anything else is left alone
+    std::fs::write(&socket_path, "# Not a socket").unwrap();
+    assert_err!(ControlServer::start(socket_path.clone(), sender));
+    assert_eq!(std::fs::read_to_string(&socket_path).unwrap(), "# Not a socket");
+}
@@ -0,0 +1,82 @@
+use std::path::{PathBuf, Path};
+
+use quickmd::assets::PageState;
+use quickmd::ui::history::History;
+
+fn scrolled_to(scroll_top: f64) -> PageState {
+    PageState { scroll_top, ..PageState::default() }
+}
+
+#[test]
+fn test_starts_at_the_given_document() {
+    let history = History::new(PathBuf::from("README.md"));
+
+    assert_eq!(history.current(), Path::new("README.md"));
+}
+
+#[test]
+fn test_pushing_moves_the_current_position() {
+    let mut history = History::new(PathBuf::from("README.md"));
+
+    history.push(PathBuf::from("docs/setup.md"), PageState::default());
+    assert_eq!(history.current(), Path::new("docs/setup.md"));
+
+    history.push(PathBuf::from("docs/usage.md"), PageState::default());
+    assert_eq!(history.current(), Path::new("docs/usage.md"));
+}
+
+#[test]
+fn test_going_back_and_forward_restores_page_state() {
+    let mut history = History::new(PathBuf::from("README.md"));
+    history.push(PathBuf::from("docs/setup.md"), scrolled_to(100.0));
+
+    let entry = history.go_back(scrolled_to(200.0)).unwrap();
+    assert_eq!(entry.path, Path::new("README.md"));
+    assert_eq!(entry.page_state.scroll_top, 100.0);
+
+    let entry = history.go_forward(scrolled_to(150.0)).unwrap();
+    assert_eq!(entry.path, Path::new("docs/setup.md"));
+    assert_eq!(entry.page_state.scroll_top, 200.0);
+
+    let entry = history.go_back(scrolled_to(250.0)).unwrap();
+    assert_eq!(entry.page_state.scroll_top, 150.0);
+}
+
+#[test]
+fn test_cannot_move_past_the_ends() {
+    let mut history = History::new(PathBuf::from("README.md"));
+
+    assert!(history.go_back(PageState::default()).is_none());
+    assert!(history.go_forward(PageState::default()).is_none());
+    assert_eq!(history.current(), Path::new("README.md"));
+
+    history.push(PathBuf::from("docs/setup.md"), PageState::default());
+    assert!(history.go_forward(PageState::default()).is_none());
+    assert_eq!(history.current(), Path::new("docs/setup.md"));
+}
+
+#[test]
+fn test_pushing_discards_forward_entries() {
+    let mut history = History::new(PathBuf::from("README.md"));
+
+    history.push(PathBuf::from("docs/setup.md"), PageState::default());
+    history.go_back(PageState::default());
+    history.push(PathBuf::from("docs/usage.md"), PageState::default());
+
+    assert!(history.go_forward(PageState::default()).is_none());
+    assert_eq!(history.go_back(PageState::default()).unwrap().path, Path::new("README.md"));
+}
+
+#[test]
+fn test_popping_undoes_a_push() {
+    let mut history = History::new(PathBuf::from("README.md"));
+    history.push(PathBuf::from("docs/setup.md"), scrolled_to(100.0));
+
+    let entry = history.pop().unwrap();
+    assert_eq!(entry.path, Path::new("README.md"));
+    assert_eq!(entry.page_state.scroll_top, 100.0);
+
+    assert!(history.pop().is_none());
+    assert!(history.go_forward(PageState::default()).is_none());
+    assert_eq!(history.current(), Path::new("README.md"));
+}
@@ -2,7 +2,7 @@ use std::path::PathBuf;
 use std::fs;
 
 use claim::assert_matches;
-use quickmd::input::InputFile;
+use quickmd::input::{DocumentFormat, InputFile, find_documents, repository_url_from_remote};
 
 #[test]
 fn test_input_file_constructed_from_local_file() {
@@ -45,3 +45,55 @@ fn test_temporary_file_is_cleaned_up_on_drop() {
     drop(input_file);
     assert!(!path.exists());
 }
+
+#[test]
+fn test_finding_documents_in_a_directory() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let root = tempdir.path();
+
+    fs::create_dir_all(root.join("docs/nested")).unwrap();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("target/doc")).unwrap();
+
+    fs::write(root.join("README.md"), "# Readme").unwrap();
+    fs::write(root.join("notes.txt"), "Not markdown").unwrap();
+    fs::write(root.join("docs/guide.markdown"), "# Guide").unwrap();
+    fs::write(root.join("docs/index.rst"), "Index\n=====").unwrap();
+    fs::write(root.join("docs/nested/deep.md"), "# Deep").unwrap();
+    fs::write(root.join(".git/hidden.md"), "# Hidden").unwrap();
+    fs::write(root.join("target/doc/generated.md"), "# Generated").unwrap();
+
+    // Symlinked directories are not followed
+    std::os::unix::fs::symlink(root.join("docs"), root.join("docs-link")).unwrap();
+
+    let files = find_documents(root);
+
+    assert_eq!(files, vec![
+        root.join("README.md"),
+        root.join("docs/guide.markdown"),
+        root.join("docs/index.rst"),
+        root.join("docs/nested/deep.md"),
+    ]);
+}
+
+#[test]
+fn test_document_format_depends_on_the_extension() {
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("README.md")), Some(DocumentFormat::Markdown));
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("notes.MARKDOWN")), Some(DocumentFormat::Markdown));
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("docs/index.rst")), Some(DocumentFormat::Rst));
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("guide.adoc")), Some(DocumentFormat::AsciiDoc));
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("notes.txt")), None);
+    assert_eq!(DocumentFormat::from_path(&PathBuf::from("Makefile")), None);
+}
+
+#[test]
+fn test_repository_url_from_git_remotes() {
+    let expected = Some(String::from("https://github.com/AndrewRadev/quickmd"));
+
+    assert_eq!(repository_url_from_remote("https://github.com/AndrewRadev/quickmd.git"), expected);
+    assert_eq!(repository_url_from_remote("https://user@github.com/AndrewRadev/quickmd/"), expected);
+    assert_eq!(repository_url_from_remote("git@github.com:AndrewRadev/quickmd.git"), expected);
+    assert_eq!(repository_url_from_remote("ssh://git@github.com:22/AndrewRadev/quickmd"), expected);
+
+    assert_eq!(repository_url_from_remote("/local/path/to/quickmd"), None);
+}
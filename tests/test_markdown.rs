@@ -1,8 +1,10 @@
 use std::collections::HashSet;
+use std::fs;
 use std::io::Write;
 
 use tempfile::NamedTempFile;
-use quickmd::markdown::Renderer;
+use quickmd::input::{Config, FrontMatterDisplay, MarkdownConfig};
+use quickmd::markdown::{Renderer, Heading};
 
 #[test]
 fn test_keeps_track_of_rendered_languages() {
@@ -21,7 +23,7 @@ fn test_keeps_track_of_rendered_languages() {
     writeln!(file, "```     rust").unwrap();
     writeln!(file, "```"         ).unwrap();
 
-    let renderer = Renderer::new(file.path().to_path_buf());
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
     let content = renderer.run().unwrap();
     let expected: HashSet<_> =
         vec!["vim", "ruby", "rust"].into_iter().map(String::from).collect();
@@ -29,6 +31,26 @@ fn test_keeps_track_of_rendered_languages() {
     assert_eq!(expected, content.code_languages);
 }
 
+#[test]
+fn test_highlights_known_languages() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "```rust"          ).unwrap();
+    writeln!(file, "fn main() {{}}"   ).unwrap();
+    writeln!(file, "```"              ).unwrap();
+    writeln!(file, ""                 ).unwrap();
+    writeln!(file, "```unknown"       ).unwrap();
+    writeln!(file, "<plain> code"     ).unwrap();
+    writeln!(file, "```"              ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<pre class="highlighted"><code class="language-rust">"#));
+    assert!(content.html.contains(r#"<span class="syn-entity syn-name syn-function syn-rust">main</span>"#));
+    assert!(content.html.contains(r#"<pre><code class="language-unknown">&lt;plain&gt; code"#));
+}
+
 #[test]
 fn test_renders_local_images() {
     let mut file = NamedTempFile::new().unwrap();
@@ -42,7 +64,7 @@ fn test_renders_local_images() {
     writeln!(file, "![demo image](http://remote-image-01.png)").unwrap();
     writeln!(file, "![demo image](https://remote-image-02.png)").unwrap();
 
-    let renderer = Renderer::new(file.path().to_path_buf());
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
     let content = renderer.run().unwrap();
 
     assert!(content.html.contains(&format!("src=\"file://{}/local-image-01.png\"", tempdir.display())));
@@ -53,3 +75,557 @@ fn test_renders_local_images() {
     assert!(content.html.contains("src=\"http://remote-image-01.png\""));
     assert!(content.html.contains("src=\"https://remote-image-02.png\""));
 }
+
+#[test]
+fn test_collects_local_images() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let root_dir = tempdir.path().canonicalize().unwrap();
+    let path = root_dir.join("file.md");
+
+    fs::create_dir(root_dir.join("images")).unwrap();
+    fs::write(root_dir.join("images/diagram.png"), "").unwrap();
+    fs::write(&path, [
+        "![diagram](./images/diagram.png)",
+        "![diagram again](images/../images/diagram.png?v=2)",
+        "![missing](missing%20image.png)",
+        "![remote](https://example.com/image.png)",
+    ].join("\n\n")).unwrap();
+
+    let renderer = Renderer::new(path, &Config::default());
+    let content = renderer.run().unwrap();
+
+    let expected: HashSet<_> = vec![
+        root_dir.join("images/diagram.png"),
+        root_dir.join("missing image.png"),
+    ].into_iter().collect();
+
+    assert_eq!(expected, content.local_images);
+}
+
+#[test]
+fn test_renders_local_links() {
+    let mut file = NamedTempFile::new().unwrap();
+    let tempdir = file.path().parent().unwrap().to_path_buf();
+
+    writeln!(file, "[license](../LICENSE)").unwrap();
+    writeln!(file, "[script](scripts/build.sh)").unwrap();
+    writeln!(file, "[docs](./docs/guide.md#usage)").unwrap();
+    writeln!(file, "[root](/README.md)").unwrap();
+    writeln!(file, "[section](#usage)").unwrap();
+    writeln!(file, "[remote](https://example.com/page)").unwrap();
+    writeln!(file, "[email](mailto:someone@example.com)").unwrap();
+    writeln!(file, "<someone@example.com>").unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(&format!("href=\"file://{}/../LICENSE\"", tempdir.display())));
+    assert!(content.html.contains(&format!("href=\"file://{}/scripts/build.sh\"", tempdir.display())));
+    assert!(content.html.contains(&format!("href=\"file://{}/docs/guide.md#usage\"", tempdir.display())));
+    assert!(content.html.contains(&format!("href=\"file://{}/README.md\"", tempdir.display())));
+    assert!(content.html.contains("href=\"#usage\""));
+    assert!(content.html.contains("href=\"https://example.com/page\""));
+    assert!(content.html.contains("href=\"mailto:someone@example.com\""));
+}
+
+#[test]
+fn test_keeps_relative_links_if_asked_to() {
+    let mut file = NamedTempFile::new().unwrap();
+    let tempdir = file.path().parent().unwrap().to_path_buf();
+
+    writeln!(file, "[docs](./docs/guide.md#usage)").unwrap();
+    writeln!(file, "[section](#usage)").unwrap();
+    writeln!(file, "![image](image.png)").unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default()).with_relative_links();
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains("href=\"./docs/guide.md#usage\""));
+    assert!(content.html.contains("href=\"#usage\""));
+    // Images are still resolved, so they can be inlined
+    assert!(content.html.contains(&format!("src=\"file://{}/image.png\"", tempdir.display())));
+}
+
+#[test]
+fn test_collects_headings() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# Title"                ).unwrap();
+    writeln!(file, ""                       ).unwrap();
+    writeln!(file, "## Using `quickmd` *now*").unwrap();
+    writeln!(file, ""                       ).unwrap();
+    writeln!(file, "```"                    ).unwrap();
+    writeln!(file, "# Not a heading"        ).unwrap();
+    writeln!(file, "```"                    ).unwrap();
+    writeln!(file, ""                       ).unwrap();
+    writeln!(file, "Other title"            ).unwrap();
+    writeln!(file, "---"                    ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.headings, vec![
+        Heading { level: 1, id: String::from("title"), text: String::from("Title") },
+        Heading { level: 2, id: String::from("using-quickmd-now"), text: String::from("Using quickmd now") },
+        Heading { level: 2, id: String::from("other-title"), text: String::from("Other title") },
+    ]);
+}
+
+#[test]
+fn test_generates_github_compatible_heading_ids() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# What's new in `v1.0`?").unwrap();
+    writeln!(file, "## Über café"           ).unwrap();
+    writeln!(file, "## snake_case & dashes-").unwrap();
+    writeln!(file, "## Emoji 🎉 rocks"      ).unwrap();
+    writeln!(file, "## [Linked](https://example.com) *text*").unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<h1 id="whats-new-in-v10">What's new in <code>v1.0</code>?</h1>"#));
+    assert!(content.html.contains(r#"<h2 id="über-café">Über café</h2>"#));
+    assert!(content.html.contains(r#"<h2 id="snake_case--dashes-">"#));
+    assert!(content.html.contains(r#"<h2 id="emoji--rocks">"#));
+    assert!(content.html.contains(r#"<h2 id="linked-text">"#));
+}
+
+#[test]
+fn test_adds_suffixes_to_duplicate_heading_ids() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# Example"  ).unwrap();
+    writeln!(file, "# Example"  ).unwrap();
+    writeln!(file, "# Example-1").unwrap();
+    writeln!(file, "# Example"  ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+    let ids: Vec<_> = content.headings.iter().map(|h| h.id.as_str()).collect();
+
+    assert_eq!(ids, vec!["example", "example-1", "example-1-1", "example-2"]);
+}
+
+#[test]
+fn test_keeps_explicit_heading_ids_verbatim() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# Title {{#MyId}}"   ).unwrap();
+    writeln!(file, "# Intro {{#example}}").unwrap();
+    writeln!(file, "# Example"           ).unwrap();
+
+    let markdown = MarkdownConfig { heading_attributes: true, ..MarkdownConfig::default() };
+    let config = Config { markdown, ..Config::default() };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+    let ids: Vec<_> = content.headings.iter().map(|h| h.id.as_str()).collect();
+
+    assert_eq!(ids, vec!["MyId", "example", "example-1"]);
+    assert!(content.html.contains(r#"<h1 id="MyId">Title</h1>"#));
+}
+
+#[test]
+fn test_renders_github_alerts() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "> [!NOTE]"          ).unwrap();
+    writeln!(file, "> Useful *details*.").unwrap();
+    writeln!(file, ""                   ).unwrap();
+    writeln!(file, "> [!warning]"       ).unwrap();
+    writeln!(file, ">"                  ).unwrap();
+    writeln!(file, "> Be careful."      ).unwrap();
+    writeln!(file, ""                   ).unwrap();
+    writeln!(file, "> [!TIP] Not alone" ).unwrap();
+    writeln!(file, ""                   ).unwrap();
+    writeln!(file, "> [!UNKNOWN]"       ).unwrap();
+    writeln!(file, "> Plain quote"      ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(
+        "<div class=\"markdown-alert markdown-alert-note\">\n\
+         <p class=\"markdown-alert-title\">Note</p>\n\
+         <p>Useful <em>details</em>.</p>\n\
+         </div>"
+    ));
+    assert!(content.html.contains(
+        "<div class=\"markdown-alert markdown-alert-warning\">\n\
+         <p class=\"markdown-alert-title\">Warning</p>\n\
+         <p>Be careful.</p>\n\
+         </div>"
+    ));
+    assert!(content.html.contains("<blockquote>\n<p>[!TIP] Not alone</p>"));
+    assert!(content.html.contains("<blockquote>\n<p>[!UNKNOWN]\nPlain quote</p>"));
+}
+
+#[test]
+fn test_renders_mermaid_blocks_as_diagrams() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "```mermaid"  ).unwrap();
+    writeln!(file, "graph TD;"   ).unwrap();
+    writeln!(file, "    A-->B;"  ).unwrap();
+    writeln!(file, "```"         ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.html, "<pre class=\"mermaid\">graph TD;\n    A--&gt;B;\n</pre>\n");
+    assert!(content.code_languages.is_empty());
+    assert!(content.has_diagrams);
+}
+
+#[test]
+fn test_marks_up_math() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "Inline $a*b*c < d$, costs $5 or $10."  ).unwrap();
+    writeln!(file, ""                                      ).unwrap();
+    writeln!(file, "$$"                                    ).unwrap();
+    writeln!(file, r"\sum_{{i=0}}^n [x_i]"                 ).unwrap();
+    writeln!(file, "$$"                                    ).unwrap();
+    writeln!(file, ""                                      ).unwrap();
+    writeln!(file, "`$not math$`"                          ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(
+        r#"<p>Inline <span class="math math-inline">$a*b*c &lt; d$</span>, costs $5 or $10.</p>"#
+    ));
+    assert!(content.html.contains(
+        "<p><span class=\"math math-display\">$$\n\\sum_{i=0}^n [x_i]\n$$</span></p>"
+    ));
+    assert!(content.html.contains("<p><code>$not math$</code></p>"));
+    assert!(content.has_math);
+}
+
+#[test]
+fn test_leaves_dollar_signs_in_links_and_images_alone() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "[$x$ link](https://host/?a=$x&b=$y \"A $title$\")" ).unwrap();
+    writeln!(file, ""                                                  ).unwrap();
+    writeln!(file, "![$alt$](https://host/image.png?a=$x&b=$y)"       ).unwrap();
+    writeln!(file, ""                                                  ).unwrap();
+    writeln!(file, "<https://host/?a=$x&b=$y>, [reference][ref]"       ).unwrap();
+    writeln!(file, ""                                                  ).unwrap();
+    writeln!(file, "[ref]: https://host/?c=$x&d=$y"                    ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        r#"<p><a href="https://host/?a=$x&amp;b=$y" title="A $title$">"#,
+        r#"<span class="math math-inline">$x$</span> link</a></p>"#, "\n",
+        r#"<p><img src="https://host/image.png?a=$x&amp;b=$y" alt="$alt$" /></p>"#, "\n",
+        r#"<p><a href="https://host/?a=$x&amp;b=$y">https://host/?a=$x&amp;b=$y</a>, "#,
+        r#"<a href="https://host/?c=$x&amp;d=$y">reference</a></p>"#, "\n",
+    ));
+}
+
+#[test]
+fn test_strips_yaml_front_matter() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "---"                ).unwrap();
+    writeln!(file, "title: Example"     ).unwrap();
+    writeln!(file, "tags: [one, <two>]" ).unwrap();
+    writeln!(file, "---"                ).unwrap();
+    writeln!(file, "# Content"          ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.front_matter, Some(serde_json::json!({
+        "title": "Example",
+        "tags": ["one", "<two>"],
+    })));
+    assert_eq!(content.html, concat!(
+        r#"<table class="front-matter">"#,
+        "<thead><tr><th>title</th><th>tags</th></tr></thead>",
+        "<tbody><tr><td>Example</td><td><table><tbody><tr><td>one</td><td>&lt;two&gt;</td></tr></tbody></table></td></tr></tbody>",
+        "</table>\n",
+        "<h1 id=\"content\">Content</h1>\n",
+    ));
+}
+
+#[test]
+fn test_strips_toml_front_matter() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "+++"                  ).unwrap();
+    writeln!(file, "title = \"Example\"") .unwrap();
+    writeln!(file, "date = 2023-01-02"    ).unwrap();
+    writeln!(file, "+++"                  ).unwrap();
+    writeln!(file, "Content"              ).unwrap();
+
+    let config = Config { front_matter: FrontMatterDisplay::Hidden, ..Config::default() };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.front_matter, Some(serde_json::json!({
+        "title": "Example",
+        "date": "2023-01-02",
+    })));
+    assert_eq!(content.html, "<p>Content</p>\n");
+}
+
+#[test]
+fn test_ignores_unclosed_front_matter() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "---"    ).unwrap();
+    writeln!(file, "Content").unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.front_matter, None);
+    assert_eq!(content.html, "<hr />\n<p>Content</p>\n");
+}
+
+#[test]
+fn test_markdown_extensions_can_be_configured() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# Heading {{#custom-id}}").unwrap();
+    writeln!(file, ""                        ).unwrap();
+    writeln!(file, "\"Quoted\" -- ~~text~~"  ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<h1 id="heading-custom-id">Heading {#custom-id}</h1>"#));
+    assert!(content.html.contains("<p>&quot;Quoted&quot; -- <del>text</del></p>"));
+
+    let markdown = MarkdownConfig {
+        strikethrough:      false,
+        smart_punctuation:  true,
+        heading_attributes: true,
+        ..MarkdownConfig::default()
+    };
+    let config = Config { markdown, ..Config::default() };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<h1 id="custom-id">Heading</h1>"#));
+    assert!(content.html.contains("<p>“Quoted” – ~~text~~</p>"));
+}
+
+#[test]
+fn test_turning_off_github_extensions() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "> [!NOTE]"                             ).unwrap();
+    writeln!(file, "> Costs $x$, see https://a.com :tada:" ).unwrap();
+    writeln!(file, ""                                      ).unwrap();
+    writeln!(file, "```mermaid"                            ).unwrap();
+    writeln!(file, "graph TD;"                             ).unwrap();
+    writeln!(file, "```"                                   ).unwrap();
+
+    let markdown = MarkdownConfig {
+        alerts:    false,
+        math:      false,
+        diagrams:  false,
+        autolinks: false,
+        emoji:     false,
+        ..MarkdownConfig::default()
+    };
+    let config = Config { markdown, ..Config::default() };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        "<blockquote>\n<p>[!NOTE]\nCosts $x$, see https://a.com :tada:</p>\n</blockquote>\n",
+        "<pre><code class=\"language-mermaid\">graph TD;\n</code></pre>\n",
+    ));
+    assert!(!content.has_math);
+    assert!(!content.has_diagrams);
+}
+
+#[test]
+fn test_autolinks_urls_references_and_mentions() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "See https://example.com/page, www.example.com and #12.").unwrap();
+    writeln!(file, "Thanks @someone, fixed in other/repo#3 but not in `#4` or me@example.com.").unwrap();
+
+    let config = Config {
+        repository_url: Some(String::from("https://github.com/AndrewRadev/quickmd")),
+        ..Config::default()
+    };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<a href="https://example.com/page">https://example.com/page</a>,"#));
+    assert!(content.html.contains(r#"<a href="http://www.example.com">www.example.com</a>"#));
+    assert!(content.html.contains(r#"<a href="https://github.com/AndrewRadev/quickmd/issues/12">#12</a>."#));
+    assert!(content.html.contains(r#"<a href="https://github.com/someone">@someone</a>"#));
+    assert!(content.html.contains(r#"<a href="https://github.com/other/repo/issues/3">other/repo#3</a>"#));
+    assert!(content.html.contains("<code>#4</code>"));
+    assert!(content.html.contains("me@example.com."));
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<a href="https://example.com/page">https://example.com/page</a>,"#));
+    assert!(content.html.contains("and #12."));
+    assert!(content.html.contains("Thanks @someone,"));
+}
+
+#[test]
+fn test_autolinked_urls_keep_balanced_parentheses() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "See https://en.wikipedia.org/wiki/Foo_(bar).").unwrap();
+    writeln!(file, "(Or https://example.com/page), (or https://example.com/(a)b).).").unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(concat!(
+        r#"<a href="https://en.wikipedia.org/wiki/Foo_(bar)">"#,
+        r#"https://en.wikipedia.org/wiki/Foo_(bar)</a>."#,
+    )));
+    assert!(content.html.contains(r#"(Or <a href="https://example.com/page">https://example.com/page</a>),"#));
+    assert!(content.html.contains(r#"(or <a href="https://example.com/(a)b">https://example.com/(a)b</a>).)."#));
+}
+
+#[test]
+fn test_doesnt_autolink_inside_html_links() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, r#"Ask <a href="https://example.com/team">@someone about #12</a> or @other."#).unwrap();
+    writeln!(file, r#"Also <A HREF="https://example.com">www.example.com</A>."#).unwrap();
+
+    let config = Config {
+        repository_url: Some(String::from("https://github.com/AndrewRadev/quickmd")),
+        ..Config::default()
+    };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<a href="https://example.com/team">@someone about #12</a>"#));
+    assert!(content.html.contains(r#"or <a href="https://github.com/other">@other</a>."#));
+    assert!(content.html.contains(r#"<A HREF="https://example.com">www.example.com</A>."#));
+}
+
+#[test]
+fn test_replaces_emoji_shortcodes() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "Released :tada: with a :white_check_mark: and a :not_an_emoji:").unwrap();
+    writeln!(file, ""                                                           ).unwrap();
+    writeln!(file, "Not in `:tada:` code spans"                                 ).unwrap();
+    writeln!(file, ""                                                           ).unwrap();
+    writeln!(file, "```"                                                        ).unwrap();
+    writeln!(file, ":warning: or code blocks"                                   ).unwrap();
+    writeln!(file, "```"                                                        ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains("<p>Released 🎉 with a ✅ and a :not_an_emoji:</p>"));
+    assert!(content.html.contains("<code>:tada:</code>"));
+    assert!(content.html.contains(":warning: or code blocks"));
+}
+
+#[test]
+fn test_sanitizes_html_if_configured() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "# Heading"                                                   ).unwrap();
+    writeln!(file, ""                                                            ).unwrap();
+    writeln!(file, "<script>alert('script')</script>"                            ).unwrap();
+    writeln!(file, ""                                                            ).unwrap();
+    writeln!(file, "<iframe src=\"https://example.com\"></iframe>"               ).unwrap();
+    writeln!(file, ""                                                            ).unwrap();
+    writeln!(file, "<p onclick=\"alert('click')\" id=\"link-preview\">Click</p>" ).unwrap();
+    writeln!(file, ""                                                            ).unwrap();
+    writeln!(file, "[link](javascript:alert('link')) and [file](other.md)"       ).unwrap();
+    writeln!(file, ""                                                            ).unwrap();
+    writeln!(file, "- [x] Done"                                                  ).unwrap();
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains("<script>"));
+    assert!(content.html.contains("onclick"));
+
+    let config = Config { sanitize_html: true, ..Config::default() };
+    let renderer = Renderer::new(file.path().to_path_buf(), &config);
+    let content = renderer.run().unwrap();
+
+    assert!(!content.html.contains("script"));
+    assert!(!content.html.contains("iframe"));
+    assert!(!content.html.contains("onclick"));
+    assert!(!content.html.contains("javascript"));
+
+    assert!(content.html.contains(r#"<h1 id="user-content-heading">Heading</h1>"#));
+    assert!(content.html.contains(r#"<p id="user-content-link-preview">Click</p>"#));
+    assert_eq!(content.headings[0].id, "heading");
+    assert!(content.html.contains(r#"<a href="file://"#));
+    assert!(content.html.contains(r#"<input disabled="" type="checkbox" checked="">"#));
+}
+
+#[test]
+fn test_renders_source_lines() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "---"              ).unwrap(); // 1
+    writeln!(file, "title: Test"      ).unwrap(); // 2
+    writeln!(file, "---"              ).unwrap(); // 3
+    writeln!(file, "# Heading"        ).unwrap(); // 4
+    writeln!(file, ""                 ).unwrap(); // 5
+    writeln!(file, "$$"               ).unwrap(); // 6
+    writeln!(file, "x^2"              ).unwrap(); // 7
+    writeln!(file, "$$"               ).unwrap(); // 8
+    writeln!(file, ""                 ).unwrap(); // 9
+    writeln!(file, "- one"            ).unwrap(); // 10
+    writeln!(file, "- two"            ).unwrap(); // 11
+    writeln!(file, ""                 ).unwrap(); // 12
+    writeln!(file, "```rust"          ).unwrap(); // 13
+    writeln!(file, "fn main() {{}}"   ).unwrap(); // 14
+    writeln!(file, "```"              ).unwrap(); // 15
+    writeln!(file, ""                 ).unwrap(); // 16
+    writeln!(file, "> [!NOTE]"        ).unwrap(); // 17
+    writeln!(file, "> Note"           ).unwrap(); // 18
+    writeln!(file, ""                 ).unwrap(); // 19
+    writeln!(file, "---"              ).unwrap(); // 20
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default());
+    let content = renderer.run().unwrap();
+
+    assert!(!content.html.contains("data-source-line"));
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default()).with_source_lines();
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains(r#"<h1 data-source-line="4" id="heading">Heading</h1>"#));
+    assert!(content.html.contains(r#"<p data-source-line="6">"#));
+    assert!(content.html.contains(r#"<ul data-source-line="10">"#));
+    assert!(content.html.contains(r#"<li data-source-line="11">two</li>"#));
+    assert!(content.html.contains(r#"<pre data-source-line="13" class="highlighted">"#));
+    assert!(content.html.contains(r#"<div data-source-line="17" class="markdown-alert"#));
+    assert!(content.html.contains(r#"<hr data-source-line="20" />"#));
+}
+
+#[test]
+fn test_source_lines_are_not_affected_by_html_comments() {
+    let mut file = NamedTempFile::new().unwrap();
+
+    writeln!(file, "Text <!-- source-line 99 -->").unwrap(); // 1
+    writeln!(file, ""                            ).unwrap(); // 2
+    writeln!(file, "> Quote"                     ).unwrap(); // 3
+
+    let renderer = Renderer::new(file.path().to_path_buf(), &Config::default()).with_source_lines();
+    let content = renderer.run().unwrap();
+
+    assert!(content.html.contains("<!-- source-line 99 -->"));
+    assert!(content.html.contains(r#"<p data-source-line="1">Text <!-- source-line 99 --></p>"#));
+    assert!(content.html.contains(r#"<blockquote data-source-line="3">"#));
+    assert!(!content.html.contains(r#"data-source-line="99""#));
+}
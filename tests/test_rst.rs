@@ -0,0 +1,210 @@
+use std::io::Write;
+
+use tempfile::Builder;
+use quickmd::input::Config;
+use quickmd::markdown::Heading;
+use quickmd::render::{self, Render};
+use quickmd::rst::Renderer;
+
+fn rst_file(contents: &str) -> tempfile::NamedTempFile {
+    let mut file = Builder::new().suffix(".rst").tempfile().unwrap();
+    write!(file, "{}", contents).unwrap();
+    file
+}
+
+#[test]
+fn test_renderer_is_picked_by_extension() {
+    let file = rst_file("Title\n=====\n");
+    let renderer = render::renderer_for(file.path().to_path_buf(), &Config::default());
+
+    assert_eq!(renderer.run().unwrap().html, "<h1 data-source-line=\"1\" id=\"title\">Title</h1>\n");
+}
+
+#[test]
+fn test_export_renderer_keeps_relative_links() {
+    let file = rst_file("See `the guide <docs/guide.rst>`_.\n");
+    let renderer = render::export_renderer_for(file.path().to_path_buf(), &Config::default());
+
+    assert_eq!(renderer.run().unwrap().html, "<p>See <a href=\"docs/guide.rst\">the guide</a>.</p>\n");
+}
+
+#[test]
+fn test_renders_headings() {
+    let file = rst_file(concat!(
+        "=====\n",
+        "Title\n",
+        "=====\n",
+        "\n",
+        "Section *one*\n",
+        "-------------\n",
+        "\n",
+        "Subsection\n",
+        "~~~~~~~~~~\n",
+        "\n",
+        "Section two\n",
+        "-----------\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.headings, vec![
+        Heading { level: 1, id: String::from("title"),       text: String::from("Title") },
+        Heading { level: 2, id: String::from("section-one"), text: String::from("Section one") },
+        Heading { level: 3, id: String::from("subsection"),  text: String::from("Subsection") },
+        Heading { level: 2, id: String::from("section-two"), text: String::from("Section two") },
+    ]);
+    assert!(content.html.contains(r#"<h2 id="section-one">Section <em>one</em></h2>"#));
+}
+
+#[test]
+fn test_renders_lists() {
+    let file = rst_file(concat!(
+        "- One\n",
+        "- Two, with a\n",
+        "  second line\n",
+        "\n",
+        "  - Nested\n",
+        "\n",
+        "3. Three\n",
+        "#. Four\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        "<ul>\n",
+        "<li>One</li>\n",
+        "<li><p>Two, with a\nsecond line</p>\n<ul>\n<li>Nested</li>\n</ul></li>\n",
+        "</ul>\n",
+        "<ol start=\"3\">\n",
+        "<li>Three</li>\n",
+        "<li>Four</li>\n",
+        "</ol>\n",
+    ));
+}
+
+#[test]
+fn test_renders_code_blocks() {
+    let file = rst_file(concat!(
+        "Example::\n",
+        "\n",
+        "    <literal> code\n",
+        "\n",
+        ".. code-block:: rust\n",
+        "\n",
+        "    fn main() {}\n",
+        "\n",
+        ".. code:: unknown\n",
+        "\n",
+        "    plain code\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert!(content.html.contains("<p>Example:</p>\n<pre><code>&lt;literal&gt; code\n</code></pre>"));
+    assert!(content.html.contains(r#"<pre class="highlighted"><code class="language-rust">"#));
+    assert!(content.html.contains(r#"<pre><code class="language-unknown">plain code"#));
+    assert_eq!(content.code_languages.len(), 2);
+}
+
+#[test]
+fn test_renders_links_and_images() {
+    let file = rst_file(concat!(
+        "See `the docs <https://example.com/docs>`_, Python_, `the guide <guide.rst>`_ and ",
+        "`Usage`_, or https://example.com.\n",
+        "\n",
+        ".. _Python: https://www.python.org\n",
+        "\n",
+        ".. image:: images/demo.png\n",
+        "   :alt: Demo\n",
+    ));
+    let tempdir = file.path().parent().unwrap().to_path_buf();
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert!(content.html.contains(r#"<a href="https://example.com/docs">the docs</a>"#));
+    assert!(content.html.contains(r#"<a href="https://www.python.org">Python</a>"#));
+    assert!(content.html.contains(&format!(r#"<a href="file://{}/guide.rst">the guide</a>"#, tempdir.display())));
+    assert!(content.html.contains(r##"<a href="#usage">Usage</a>"##));
+    assert!(content.html.contains(r#"<a href="https://example.com">https://example.com</a>."#));
+    assert!(content.html.contains(&format!(r#"<img src="file://{}/images/demo.png" alt="Demo" />"#, tempdir.display())));
+    assert!(!content.html.contains("python.org</p>"));
+
+    assert_eq!(content.local_images.into_iter().collect::<Vec<_>>(), vec![tempdir.join("images/demo.png")]);
+}
+
+#[test]
+fn test_renders_escaped_characters() {
+    let file = rst_file(concat!(
+        "\\*Not emphasis\\*, *real\\* emphasis*, **strong** and a \\\\ backslash.\n",
+        "\n",
+        "Literals like ``C:\\path\\to\\*file*`` are kept as they are.\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+
+    assert_eq!(content.html, concat!(
+        "<p>*Not emphasis*, <em>real* emphasis</em>, <strong>strong</strong> and a \\ backslash.</p>\n",
+        "<p>Literals like <code>C:\\path\\to\\*file*</code> are kept as they are.</p>\n",
+    ));
+}
+
+#[test]
+fn test_only_lines_starting_with_two_dots_and_a_space_are_explicit_markup() {
+    let file = rst_file(concat!(
+        "...and then it broke.\n",
+        "\n",
+        "..\n",
+        "   An empty comment with a body\n",
+        "\n",
+        "..not a comment either\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+    assert_eq!(content.html, concat!(
+        "<p>...and then it broke.</p>\n",
+        "<p>..not a comment either</p>\n",
+    ));
+}
+
+#[test]
+fn test_sanitizes_html_if_configured() {
+    let file = rst_file("A `link <javascript:alert(1)>`_ and a `safe one <https://example.com>`_.\n");
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+    assert!(content.html.contains(r#"href="javascript:alert(1)""#));
+
+    let config = Config { sanitize_html: true, ..Config::default() };
+    let content = Renderer::new(file.path().to_path_buf(), &config).run().unwrap();
+    assert!(!content.html.contains("javascript:"));
+    assert!(content.html.contains(r#"<a href="https://example.com">safe one</a>"#));
+}
+
+#[test]
+fn test_renders_source_lines() {
+    let file = rst_file(concat!(
+        "Title\n",
+        "=====\n",
+        "\n",
+        "A paragraph\n",
+        "on two lines.\n",
+        "\n",
+        ".. A comment\n",
+        "\n",
+        "- One\n",
+        "- Two\n",
+        "\n",
+        "----\n",
+    ));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).run().unwrap();
+    assert!(!content.html.contains("data-source-line"));
+
+    let content = Renderer::new(file.path().to_path_buf(), &Config::default()).with_source_lines().run().unwrap();
+    assert_eq!(content.html, concat!(
+        "<h1 data-source-line=\"1\" id=\"title\">Title</h1>\n",
+        "<p data-source-line=\"4\">A paragraph\non two lines.</p>\n",
+        "<ul data-source-line=\"9\">\n<li>One</li>\n<li>Two</li>\n</ul>\n",
+        "<hr data-source-line=\"12\" />\n",
+    ));
+}
@@ -21,7 +21,15 @@ fn test_default_keybindings() {
 
     assert_eq!(keymaps.get_action(ModifierType::CONTROL_MASK, Key::from_unicode('q')), Action::Quit);
     assert_eq!(keymaps.get_action(ModifierType::empty(), Key::from_name("F1")), Action::ShowHelp);
+    assert_eq!(keymaps.get_action(ModifierType::CONTROL_MASK, Key::from_unicode('p')), Action::ExportPdf);
 
+    assert_eq!(keymaps.get_action(ModifierType::CONTROL_MASK, Key::from_name("Page_Down")), Action::NextTab);
+    assert_eq!(keymaps.get_action(ModifierType::CONTROL_MASK, Key::from_name("Page_Up")), Action::PreviousTab);
+    assert_eq!(keymaps.get_action(ModifierType::CONTROL_MASK, Key::from_unicode('w')), Action::CloseTab);
+
+    assert_eq!(keymaps.get_action(ModifierType::MOD1_MASK, Key::from_name("Left")), Action::GoBack);
+    assert_eq!(keymaps.get_action(ModifierType::MOD1_MASK, Key::from_name("Right")), Action::GoForward);
+    assert_eq!(keymaps.get_action(ModifierType::empty(), Key::from_unicode('o')), Action::ToggleOutline);
 }
 
 #[test]